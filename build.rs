@@ -0,0 +1,51 @@
+//! Generates the day modules and the solution registry from the
+//! `yearYYYY/dayN.rs` files in `src/`, so adding a day only requires
+//! creating its module.
+
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+/// Lists the numbers in the names of the entries of a directory that have
+/// the given prefix and suffix, in order.
+fn numbered(dir: &Path, prefix: &str, suffix: &str) -> Vec<usize> {
+    let mut numbers: Vec<usize> = fs::read_dir(dir)
+        .expect("src directory should be readable")
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            name.strip_prefix(prefix)?
+                .strip_suffix(suffix)?
+                .parse()
+                .ok()
+        })
+        .collect();
+    numbers.sort();
+    numbers
+}
+
+fn main() {
+    let src = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+    println!("cargo:rerun-if-changed={}", src.display());
+
+    let mut modules = String::new();
+    let mut registrations = String::from("&[\n");
+    for year in numbered(&src, "year", "") {
+        modules += &format!("pub mod year{year} {{\n    pub const YEAR: usize = {year};\n\n");
+
+        let dir = src.join(format!("year{year}"));
+        for day in numbered(&dir, "day", ".rs") {
+            let path = dir.join(format!("day{day}.rs"));
+            modules += &format!(
+                "    #[path = {:?}]\n    pub mod day{day};\n",
+                path.display().to_string()
+            );
+            registrations += &format!("    crate::year{year}::day{day}::REGISTRATION,\n");
+        }
+        modules += "}\n";
+    }
+    registrations += "]\n";
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out.join("days.rs"), modules).expect("day modules should be writable");
+    fs::write(out.join("registrations.rs"), registrations)
+        .expect("registrations should be writable");
+}
@@ -0,0 +1,161 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// The answer to one part of a day's puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "Repr", into = "Repr")]
+pub enum Answer {
+    /// A number, which is what most puzzles ask for
+    Int(i64),
+    /// Text, such as the labels on the top crates of Day 5
+    Text(String),
+    /// An image drawn row by row, where lit pixels are `true`
+    Pixels(Vec<Vec<bool>>),
+}
+
+impl Answer {
+    /// Whether the answer needs more than one line to display.
+    pub fn is_multiline(&self) -> bool {
+        match self {
+            Answer::Int(_) => false,
+            Answer::Text(text) => text.contains('\n'),
+            Answer::Pixels(rows) => rows.len() > 1,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Pixels(rows) => {
+                let rows: Vec<String> = rows.iter().map(|row| render_row(row)).collect();
+                write!(f, "{}", rows.join("\n"))
+            }
+        }
+    }
+}
+
+fn render_row(row: &[bool]) -> String {
+    row.iter().map(|&lit| if lit { '#' } else { '.' }).collect()
+}
+
+/// How answers are serialized: numbers, strings, or a list of rows of `#` and `.`.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Repr {
+    Int(i64),
+    Text(String),
+    Rows(Vec<String>),
+}
+
+impl From<Repr> for Answer {
+    fn from(repr: Repr) -> Self {
+        match repr {
+            Repr::Int(n) => Answer::Int(n),
+            Repr::Text(text) => Answer::Text(text),
+            Repr::Rows(rows) => Answer::Pixels(
+                rows.iter()
+                    .map(|row| row.chars().map(|c| c == '#').collect())
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<Answer> for Repr {
+    fn from(answer: Answer) -> Self {
+        match answer {
+            Answer::Int(n) => Repr::Int(n),
+            Answer::Text(text) => Repr::Text(text),
+            Answer::Pixels(rows) => Repr::Rows(rows.iter().map(|row| render_row(row)).collect()),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(n: $int) -> Self {
+                    // Numbers too large for an `i64` are kept exactly as text
+                    match i64::try_from(n) {
+                        Ok(n) => Answer::Int(n),
+                        Err(_) => Answer::Text(n.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i32, u32, i64, u64, isize, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<Vec<Vec<bool>>> for Answer {
+    fn from(rows: Vec<Vec<bool>>) -> Self {
+        Answer::Pixels(rows)
+    }
+}
+
+impl PartialEq<i64> for Answer {
+    fn eq(&self, other: &i64) -> bool {
+        matches!(self, Answer::Int(n) if n == other)
+    }
+}
+
+/// Compares the answer as it is displayed.
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        self.to_string().as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(Answer::from(24000usize), 24000);
+        assert_eq!(Answer::from(24000usize), "24000");
+        assert_eq!(Answer::from("CMZ"), "CMZ");
+        assert_ne!(Answer::from("24000"), 24000);
+        assert_eq!(Answer::from(u64::MAX), Answer::Text(u64::MAX.to_string()));
+    }
+
+    #[test]
+    fn test_serialize() {
+        let answers = vec![
+            Answer::Int(13140),
+            Answer::from("MCD"),
+            Answer::Pixels(vec![vec![true, false], vec![false, true]]),
+        ];
+
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(json, r##"[13140,"MCD",["#.",".#"]]"##);
+
+        let parsed: Vec<Answer> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, answers);
+        assert_eq!(answers[2].to_string(), "#.\n.#");
+    }
+}
@@ -0,0 +1,117 @@
+use std::time::{Duration, Instant};
+
+use crate::utils::time_execution;
+
+/// How many times to run each stage of a day when benchmarking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+    /// Runs made before measuring, which aren't recorded
+    pub warmup: usize,
+    /// The most runs to record
+    pub iterations: usize,
+    /// Stop recording once a stage has been running for this long
+    pub budget: Duration,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            warmup: 3,
+            iterations: 100,
+            budget: Duration::from_secs(3),
+        }
+    }
+}
+
+/// Statistics, in seconds, for the recorded runs of a stage.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    /// Summarises the duration of each run, which mustn't be empty.
+    pub fn from_samples(samples: &[f64]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+
+        let runs = sorted.len();
+        let median = match runs % 2 {
+            0 => (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2.0,
+            _ => sorted[runs / 2],
+        };
+        let mean = sorted.iter().sum::<f64>() / runs as f64;
+        let variance = match runs {
+            1 => 0.0,
+            _ => sorted.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (runs - 1) as f64,
+        };
+
+        Stats {
+            runs,
+            min: sorted[0],
+            median,
+            mean,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+/// Runs a function repeatedly, recording how long each run takes. The result
+/// of each run is dropped outside of the timing.
+pub fn measure<T>(settings: &Settings, func: impl Fn() -> T) -> Stats {
+    for _ in 0..settings.warmup {
+        func();
+    }
+
+    let start = Instant::now();
+    let mut samples = Vec::with_capacity(settings.iterations);
+    while samples.is_empty()
+        || (samples.len() < settings.iterations && start.elapsed() < settings.budget)
+    {
+        let (_result, seconds) = time_execution(&func);
+        samples.push(seconds as f64);
+    }
+
+    Stats::from_samples(&samples)
+}
+
+/// Formats a duration in seconds with the most readable unit.
+pub fn format_duration(seconds: f64) -> String {
+    if seconds < 1e-6 {
+        format!("{:.1} ns", seconds * 1e9)
+    } else if seconds < 1e-3 {
+        format!("{:.2} µs", seconds * 1e6)
+    } else if seconds < 1.0 {
+        format!("{:.2} ms", seconds * 1e3)
+    } else {
+        format!("{:.2} s", seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&[4.0, 1.0, 3.0, 2.0]);
+
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 2.5);
+        assert_eq!(stats.mean, 2.5);
+        assert!((stats.stddev - 1.290_994).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(0.000_000_012_5), "12.5 ns");
+        assert_eq!(format_duration(0.000_012_5), "12.50 µs");
+        assert_eq!(format_duration(0.012_5), "12.50 ms");
+        assert_eq!(format_duration(12.5), "12.50 s");
+    }
+}
@@ -0,0 +1,565 @@
+use std::time::Duration;
+
+use adventofcode2022::bench::Settings;
+use adventofcode2022::client;
+use adventofcode2022::input::InputSource;
+use adventofcode2022::{params, registry};
+
+pub const USAGE: &str = "\
+usage: adventofcode2022 [run] [<year>] <days> [--part <part>] [--param <key>=<value>]...
+                        [--example | --input <path>] [--format <format>]
+       adventofcode2022 bench [<year>] <days> [--part <part>] [--param <key>=<value>]...
+                        [--example | --input <path>] [bench options]
+       adventofcode2022 record [<year>] <days>
+       adventofcode2022 verify [<year>] <days>
+       adventofcode2022 fetch [<year>] <days> [--base-url <url>]
+       adventofcode2022 submit [<year>] <day> <part> [--base-url <url>]
+       adventofcode2022 new [<year>] <day> [--title <title>]
+       adventofcode2022 list
+
+<year> defaults to the most recent year with a solution.
+<days> is a day, `all`, a range like `3..=9` or a list like `1,4,10..=12`.
+
+`record` saves the answers to the puzzle input in .answers/{year}/{day}.json
+as confirmed, and `verify` fails if any answer differs from the confirmed one.
+
+`fetch` downloads the puzzle input to .input/{year}/{day}.txt, using the
+session cookie in .session. Inputs that have already been downloaded are kept.
+
+`submit` sends the answer to a part for the puzzle input. Accepted answers
+are recorded as confirmed, and rejected ones are never sent again.
+
+`new` creates src/year{year}/day{day}.rs from a template, along with an empty
+example in .test_input/{year}/{day}.txt. Existing files are never replaced.
+
+options:
+  -p, --part <part>     only solve part 1 or part 2
+  --param <key>=<value> override a constant of the puzzle, such as the row
+                        searched by day 15 with `y_target=10` for its example
+  -e, --example         read the example from .test_input/{year}/{day}.txt
+  -i, --input <path>    read the input from <path>, or from stdin when <path> is -
+  -f, --format <format> print the results as `text` (default), `json` or `csv`
+
+bench options:
+  -n, --iterations <n>  record at most <n> runs of each stage (default 100)
+  --warmup <n>          unrecorded runs before measuring each stage (default 3)
+  --budget <seconds>    stop recording a stage after this long (default 3)
+
+fetch and submit options:
+  --base-url <url>      the site to use (default https://adventofcode.com)";
+
+/// The commands given as the first argument, which change the options allowed.
+const MODES: &[&str] = &["run", "bench", "record", "verify", "fetch", "submit", "new"];
+
+/// The first year of Advent of Code, which tells years and days apart.
+const FIRST_YEAR: usize = 2015;
+
+/// How to print the results of solving days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Readable output, with a summary table when solving several days
+    Text,
+    /// A JSON array with an object for each part
+    Json,
+    /// A header row followed by a row for each part
+    Csv,
+}
+
+/// What the binary has been asked to do.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Help,
+    List,
+    /// Solves the days, only solving `part` when there is one, with the
+    /// `(key, value)` pairs in `params` overriding the puzzle's constants
+    Solve {
+        year: usize,
+        days: Vec<usize>,
+        part: Option<u8>,
+        params: Vec<(String, String)>,
+        input: InputSource,
+        format: Format,
+    },
+    Bench {
+        year: usize,
+        days: Vec<usize>,
+        part: Option<u8>,
+        params: Vec<(String, String)>,
+        input: InputSource,
+        settings: Settings,
+    },
+    Record {
+        year: usize,
+        days: Vec<usize>,
+    },
+    Verify {
+        year: usize,
+        days: Vec<usize>,
+    },
+    Fetch {
+        year: usize,
+        days: Vec<usize>,
+        base_url: String,
+    },
+    Submit {
+        year: usize,
+        day: usize,
+        part: u8,
+        base_url: String,
+    },
+    New {
+        year: usize,
+        day: usize,
+        title: String,
+    },
+}
+
+impl Command {
+    /// Parses the command from the arguments, excluding the binary name.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+        let mut args = args.into_iter().peekable();
+        let mut year = None;
+        let mut days = None;
+        let mut input = InputSource::Puzzle;
+        let mut format = Format::Text;
+        let mut part = None;
+        let mut params = Vec::new();
+        let mut title = None;
+        let mut base_url = client::BASE_URL.to_string();
+        let mode = args
+            .next_if(|arg| MODES.contains(&arg.as_str()))
+            .filter(|mode| mode != "run");
+        let mut settings = (mode.as_deref() == Some("bench")).then(Settings::default);
+
+        while let Some(arg) = args.next() {
+            match (arg.as_str(), settings.as_mut()) {
+                ("help" | "-h" | "--help", _) => return Ok(Command::Help),
+                ("list", _) => return Ok(Command::List),
+                ("-p" | "--part", _) if matches!(mode.as_deref(), None | Some("bench")) => {
+                    let level = args.next().ok_or("--part needs 1 or 2")?;
+                    part = Some(parse_part(&level)?);
+                }
+                ("--param", _) if matches!(mode.as_deref(), None | Some("bench")) => {
+                    let param = args.next().and_then(|param| params::split(&param));
+                    params.push(param.ok_or("--param needs a <key>=<value> pair")?);
+                }
+                ("-e" | "--example", _) => input = InputSource::Example,
+                ("-i" | "--input", _) => {
+                    let path = args.next().ok_or("--input needs a path, or - for stdin")?;
+                    input = InputSource::File(path.into());
+                }
+                ("-f" | "--format", _) if mode.is_none() => {
+                    format = match args.next().as_deref() {
+                        Some("text") => Format::Text,
+                        Some("json") => Format::Json,
+                        Some("csv") => Format::Csv,
+                        _ => return Err("--format needs one of `text`, `json` or `csv`".into()),
+                    };
+                }
+                ("--base-url", _) if matches!(mode.as_deref(), Some("fetch" | "submit")) => {
+                    base_url = args.next().ok_or("--base-url needs a URL")?;
+                }
+                ("--title", _) if mode.as_deref() == Some("new") => {
+                    title = Some(args.next().ok_or("--title needs the puzzle's title")?);
+                }
+                ("-n" | "--iterations", Some(settings)) => {
+                    settings.iterations = count(&arg, args.next())?.max(1);
+                }
+                ("--warmup", Some(settings)) => settings.warmup = count(&arg, args.next())?,
+                ("--budget", Some(settings)) => {
+                    let seconds = args.next().and_then(|s| s.parse::<f64>().ok());
+                    settings.budget = seconds
+                        .and_then(|s| Duration::try_from_secs_f64(s).ok())
+                        .ok_or("--budget needs a number of seconds")?;
+                }
+                (flag, _) if flag.starts_with('-') => {
+                    return Err(format!("Unknown option `{flag}`"))
+                }
+                (number, _) if year.is_none() && days.is_none() && is_year(number) => {
+                    year = number.parse().ok();
+                }
+                (spec, _) if days.is_none() => days = Some(spec.to_string()),
+                (level, _) if mode.as_deref() == Some("submit") && part.is_none() => {
+                    part = Some(parse_part(level)?);
+                }
+                (other, _) => return Err(format!("Unexpected argument `{other}`")),
+            }
+        }
+
+        let year = match year.or_else(registry::latest_year) {
+            Some(year) => year,
+            None => return Err("Must provide a year, there are no solutions yet".to_string()),
+        };
+        let days = parse_days(year, &days.ok_or("Must provide a day to solve")?)?;
+        if matches!(input, InputSource::File(_)) && days.len() > 1 {
+            return Err("--input can only be used when solving a single day".to_string());
+        }
+        if !params.is_empty() && days.len() > 1 {
+            return Err("--param can only be used when solving a single day".to_string());
+        }
+        match (mode.as_deref(), settings) {
+            (Some("record" | "verify"), _) if input != InputSource::Puzzle => {
+                Err("Answers can only be recorded and verified for the puzzle input".to_string())
+            }
+            (Some("record"), _) => Ok(Command::Record { year, days }),
+            (Some("verify"), _) => Ok(Command::Verify { year, days }),
+            (Some("fetch" | "submit"), _) if input != InputSource::Puzzle => {
+                Err("Only the puzzle input can be fetched or submitted".to_string())
+            }
+            (Some("fetch"), _) => Ok(Command::Fetch {
+                year,
+                days,
+                base_url,
+            }),
+            (Some("submit"), _) => match (&days[..], part) {
+                (&[day], Some(part)) => Ok(Command::Submit {
+                    year,
+                    day,
+                    part,
+                    base_url,
+                }),
+                (_, None) => Err("Must provide the part to submit".to_string()),
+                _ => Err("Only one day can be submitted at a time".to_string()),
+            },
+            (Some("new"), _) => match days[..] {
+                [day] => Ok(Command::New {
+                    year,
+                    day,
+                    title: title.unwrap_or(format!("Day {day}")),
+                }),
+                _ => Err("Only one day can be created at a time".to_string()),
+            },
+            (_, Some(settings)) => Ok(Command::Bench {
+                year,
+                days,
+                part,
+                params,
+                input,
+                settings,
+            }),
+            (_, None) => Ok(Command::Solve {
+                year,
+                days,
+                part,
+                params,
+                input,
+                format,
+            }),
+        }
+    }
+}
+
+/// Whether an argument is a year rather than a selection of days.
+fn is_year(arg: &str) -> bool {
+    arg.parse::<usize>().is_ok_and(|year| year >= FIRST_YEAR)
+}
+
+/// Parses the number of a part, which must be 1 or 2.
+fn parse_part(level: &str) -> Result<u8, String> {
+    match level {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("Part `{level}` must be 1 or 2")),
+    }
+}
+
+/// Parses the count given as the value for an option.
+fn count(option: &str, value: Option<String>) -> Result<usize, String> {
+    value
+        .and_then(|value| value.parse().ok())
+        .ok_or(format!("{option} needs a whole number"))
+}
+
+/// Parses a selection of days such as `all`, `7`, `3..=9`, `3..9` or
+/// `1,4,10..=12`, where `all` is every day of the year with a solution.
+fn parse_days(year: usize, spec: &str) -> Result<Vec<usize>, String> {
+    if spec == "all" {
+        let days: Vec<usize> = registry::days(year)
+            .map(|registration| registration.day)
+            .collect();
+        if days.is_empty() {
+            return Err(format!("There are no solutions for {year}"));
+        }
+        return Ok(days);
+    }
+
+    let number = |n: &str| {
+        n.trim()
+            .parse::<usize>()
+            .map_err(|_| format!("Provided day `{n}` wasn't a valid integer"))
+    };
+
+    let mut days = Vec::new();
+    for part in spec.split(',') {
+        let no_days = || format!("`{part}` doesn't select any days");
+        let range = if let Some((start, end)) = part.split_once("..=") {
+            number(start)?..=number(end)?
+        } else if let Some((start, end)) = part.split_once("..") {
+            let end = number(end)?.checked_sub(1).ok_or_else(no_days)?;
+            number(start)?..=end
+        } else {
+            let day = number(part)?;
+            day..=day
+        };
+
+        // Both ends are checked before the days are listed, so a huge range
+        // is rejected rather than filling memory
+        if range.is_empty() {
+            return Err(no_days());
+        }
+        let ends = [*range.start(), *range.end()];
+        if let Some(day) = ends.into_iter().find(|day| !(1..=25).contains(day)) {
+            return Err(format!("Day {day} isn't between 1 and 25"));
+        }
+        days.extend(range);
+    }
+    days.sort();
+    days.dedup();
+    Ok(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Command, String> {
+        Command::parse(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days(2022, "7"), Ok(vec![7]));
+        assert_eq!(parse_days(2022, "3..=5"), Ok(vec![3, 4, 5]));
+        assert_eq!(parse_days(2022, "3..5"), Ok(vec![3, 4]));
+        assert_eq!(parse_days(2022, "9,1..=2,2"), Ok(vec![1, 2, 9]));
+        assert_eq!(
+            parse_days(2022, "all").unwrap().len(),
+            registry::days(2022).count()
+        );
+        assert!(parse_days(2015, "all").is_err());
+        assert!(parse_days(2022, "5..=3").is_err());
+        assert!(parse_days(2022, "one").is_err());
+        assert!(parse_days(2022, "24..=26").is_err());
+        assert!(parse_days(2022, "1..=99999999999").is_err());
+        assert!(parse_days(2022, "1,9..=3").is_err());
+        assert!(parse_days(2022, "1,3..1").is_err());
+        assert!(parse_days(2022, "0..1").is_err());
+        assert_eq!(parse_days(2022, "24..26"), Ok(vec![24, 25]));
+    }
+
+    #[test]
+    fn test_parse_year() {
+        let solve = |year, days| {
+            Ok(Command::Solve {
+                year,
+                days,
+                part: None,
+                params: Vec::new(),
+                input: InputSource::Puzzle,
+                format: Format::Text,
+            })
+        };
+
+        assert_eq!(parse("run 2022 14"), solve(2022, vec![14]));
+        assert_eq!(parse("2021 1..=2"), solve(2021, vec![1, 2]));
+        assert_eq!(
+            parse("14"),
+            solve(registry::latest_year().unwrap(), vec![14])
+        );
+        assert_eq!(
+            parse("new 2023 1"),
+            Ok(Command::New {
+                year: 2023,
+                day: 1,
+                title: "Day 1".to_string(),
+            })
+        );
+        assert!(parse("2022").is_err());
+        assert!(parse("2022 2021").is_err());
+    }
+
+    #[test]
+    fn test_parse_input_source() {
+        let solve = |days: Vec<usize>, input| {
+            Ok(Command::Solve {
+                year: 2022,
+                days,
+                part: None,
+                params: Vec::new(),
+                input,
+                format: Format::Text,
+            })
+        };
+
+        assert_eq!(parse("4"), solve(vec![4], InputSource::Puzzle));
+        assert_eq!(parse("1..=2 -e"), solve(vec![1, 2], InputSource::Example));
+        assert_eq!(
+            parse("4 --input -"),
+            solve(vec![4], InputSource::File("-".into()))
+        );
+        assert!(parse("1,2 --input other.txt").is_err());
+        assert!(parse("4 --input").is_err());
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!(
+            parse("16 --part 2 -e"),
+            Ok(Command::Solve {
+                year: 2022,
+                days: vec![16],
+                part: Some(2),
+                params: Vec::new(),
+                input: InputSource::Example,
+                format: Format::Text,
+            })
+        );
+        assert!(matches!(
+            parse("bench 17 -p 1"),
+            Ok(Command::Bench { part: Some(1), .. })
+        ));
+        assert!(parse("16 --part 3").is_err());
+        assert!(parse("16 --part").is_err());
+        assert!(parse("record 16 --part 1").is_err());
+    }
+
+    #[test]
+    fn test_parse_params() {
+        assert_eq!(
+            parse("15 -e --param y_target=10 --param limit=20"),
+            Ok(Command::Solve {
+                year: 2022,
+                days: vec![15],
+                part: None,
+                params: vec![
+                    ("y_target".to_string(), "10".to_string()),
+                    ("limit".to_string(), "20".to_string()),
+                ],
+                input: InputSource::Example,
+                format: Format::Text,
+            })
+        );
+        assert!(matches!(
+            parse("bench 11 --param rounds_two=100"),
+            Ok(Command::Bench { params, .. }) if params.len() == 1
+        ));
+        assert!(parse("15 --param y_target").is_err());
+        assert!(parse("15 --param").is_err());
+        assert!(parse("11..=15 --param y_target=10").is_err());
+        assert!(parse("submit 15 1 --param y_target=10").is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        let settings = Settings {
+            warmup: 0,
+            iterations: 10,
+            budget: Duration::from_millis(500),
+        };
+        assert_eq!(
+            parse("bench 1..=2 -e -n 10 --warmup 0 --budget 0.5"),
+            Ok(Command::Bench {
+                year: 2022,
+                days: vec![1, 2],
+                part: None,
+                params: Vec::new(),
+                input: InputSource::Example,
+                settings,
+            })
+        );
+        assert!(parse("3 --warmup 2").is_err());
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!(
+            parse("all --format csv"),
+            Ok(Command::Solve {
+                year: 2022,
+                days: parse_days(2022, "all").unwrap(),
+                part: None,
+                params: Vec::new(),
+                input: InputSource::Puzzle,
+                format: Format::Csv,
+            })
+        );
+        assert!(parse("7 -f yaml").is_err());
+        assert!(parse("bench 7 -f json").is_err());
+    }
+
+    #[test]
+    fn test_parse_client() {
+        assert_eq!(
+            parse("fetch 1..=2 --base-url http://localhost:8000"),
+            Ok(Command::Fetch {
+                year: 2022,
+                days: vec![1, 2],
+                base_url: "http://localhost:8000".to_string(),
+            })
+        );
+        assert_eq!(
+            parse("fetch 7"),
+            Ok(Command::Fetch {
+                year: 2022,
+                days: vec![7],
+                base_url: client::BASE_URL.to_string(),
+            })
+        );
+        assert!(parse("fetch 26").is_err());
+        assert_eq!(
+            parse("submit 7 2"),
+            Ok(Command::Submit {
+                year: 2022,
+                day: 7,
+                part: 2,
+                base_url: client::BASE_URL.to_string(),
+            })
+        );
+        assert!(parse("submit 7").is_err());
+        assert!(parse("submit 7 3").is_err());
+        assert!(parse("submit 1,2 1").is_err());
+        assert!(parse("7 --base-url http://localhost:8000").is_err());
+    }
+
+    #[test]
+    fn test_parse_new() {
+        assert_eq!(
+            parse("new 19"),
+            Ok(Command::New {
+                year: 2022,
+                day: 19,
+                title: "Day 19".to_string(),
+            })
+        );
+        assert_eq!(
+            Command::parse(["new", "19", "--title", "Not Enough Minerals"].map(String::from)),
+            Ok(Command::New {
+                year: 2022,
+                day: 19,
+                title: "Not Enough Minerals".to_string(),
+            })
+        );
+        assert!(parse("new 0").is_err());
+        assert!(parse("new 19..=20").is_err());
+    }
+
+    #[test]
+    fn test_parse_ledger() {
+        assert_eq!(
+            parse("record 7"),
+            Ok(Command::Record {
+                year: 2022,
+                days: vec![7]
+            })
+        );
+        assert_eq!(
+            parse("verify 1..=2"),
+            Ok(Command::Verify {
+                year: 2022,
+                days: vec![1, 2]
+            })
+        );
+        assert!(parse("verify 7 --example").is_err());
+        assert!(parse("record 7 -n 5").is_err());
+    }
+}
@@ -0,0 +1,391 @@
+use std::path::Path;
+use std::time::Duration;
+use std::{fs, io};
+
+use crate::error::{ClientError, Error};
+use crate::ledger::Hint;
+use crate::utils::Answer;
+
+/// The site the puzzles are fetched from, unless another is configured.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// The file holding the session cookie from the Advent of Code site.
+pub const SESSION_PATH: &str = ".session";
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong(Hint),
+    /// An answer was submitted too recently, so this one wasn't checked
+    Wait(Duration),
+    /// The part has already been solved, or part one hasn't been yet
+    WrongLevel,
+    /// A response that couldn't be understood, as the text of its message
+    Unknown(String),
+}
+
+/// A client for the Advent of Code site, authenticated by a session cookie.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(10))
+            .user_agent(concat!(
+                env!("CARGO_PKG_NAME"),
+                "/",
+                env!("CARGO_PKG_VERSION")
+            ))
+            .build();
+
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent,
+        }
+    }
+
+    /// Creates a client with the session cookie read from a file.
+    pub fn from_session_file(base_url: &str, path: impl AsRef<Path>) -> Result<Client, Error> {
+        let path = path.as_ref();
+        let session = match fs::read_to_string(path) {
+            Ok(session) => session,
+            Err(source) if source.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(source) => {
+                return Err(Error::Io {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+        };
+
+        match session.trim() {
+            "" => Err(ClientError::MissingSession(path.to_path_buf()).into()),
+            session => Ok(Client::new(base_url, session)),
+        }
+    }
+
+    /// The URL of a page for a day's puzzle, such as its `input`.
+    fn url(&self, year: usize, day: usize, page: &str) -> String {
+        format!("{}/{year}/day/{day}/{page}", self.base_url)
+    }
+
+    /// Downloads the puzzle input for a day.
+    pub fn fetch_input(&self, year: usize, day: usize) -> Result<String, ClientError> {
+        let request = self
+            .agent
+            .get(&self.url(year, day, "input"))
+            .set("Cookie", &format!("session={}", self.session));
+
+        match request.call() {
+            Ok(response) => response
+                .into_string()
+                .map_err(|error| ClientError::Network(error.to_string())),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(status_error(day, code, body))
+            }
+            Err(error) => Err(ClientError::Network(error.to_string())),
+        }
+    }
+
+    /// Submits the answer to a part of a day's puzzle.
+    pub fn submit(
+        &self,
+        year: usize,
+        day: usize,
+        part: u8,
+        answer: &Answer,
+    ) -> Result<Outcome, ClientError> {
+        let request = self
+            .agent
+            .post(&self.url(year, day, "answer"))
+            .set("Cookie", &format!("session={}", self.session));
+        let level = part.to_string();
+        let answer = answer.to_string();
+
+        match request.send_form(&[("level", &level), ("answer", &answer)]) {
+            Ok(response) => response
+                .into_string()
+                .map(|html| parse_outcome(&html))
+                .map_err(|error| ClientError::Network(error.to_string())),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(status_error(day, code, body))
+            }
+            Err(error) => Err(ClientError::Network(error.to_string())),
+        }
+    }
+
+    /// Downloads the puzzle input for a day into a file, unless the file
+    /// already exists. Returns whether the input was downloaded.
+    pub fn cache_input(
+        &self,
+        year: usize,
+        day: usize,
+        path: impl AsRef<Path>,
+    ) -> Result<bool, Error> {
+        let path = path.as_ref();
+        if path.exists() {
+            return Ok(false);
+        }
+
+        let input = self.fetch_input(year, day)?;
+        let io_error = |source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        fs::write(path, input).map_err(io_error)?;
+        Ok(true)
+    }
+}
+
+/// Works out why the site refused a request for a day.
+fn status_error(day: usize, code: u16, body: String) -> ClientError {
+    match code {
+        // Without a valid session, inputs are refused with "Please log in"
+        400 | 401 | 403 => ClientError::SessionExpired,
+        _ if body.contains("log in") => ClientError::SessionExpired,
+        // Puzzles that haven't been released yet are missing
+        404 => ClientError::NotUnlocked(day),
+        _ => ClientError::Status {
+            code,
+            body: message(&body),
+        },
+    }
+}
+
+/// Reads the outcome of submitting an answer from the message in the page.
+pub fn parse_outcome(html: &str) -> Outcome {
+    let message = message(html);
+
+    if message.contains("That's the right answer") {
+        Outcome::Correct
+    } else if message.contains("That's not the right answer") {
+        let hint = if message.contains("answer is too high") {
+            Hint::TooHigh
+        } else if message.contains("answer is too low") {
+            Hint::TooLow
+        } else {
+            Hint::Wrong
+        };
+        Outcome::Wrong(hint)
+    } else if message.contains("You gave an answer too recently") {
+        Outcome::Wait(wait_time(&message).unwrap_or(Duration::from_secs(60)))
+    } else if message.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unknown(message)
+    }
+}
+
+/// The text of the `<article>` holding the message, or of the whole page if
+/// there isn't one, without any tags.
+fn message(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads a wait such as "You have 4m 31s left to wait" from a message.
+fn wait_time(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (time, _) = rest.split_once(" left to wait")?;
+
+    let mut seconds = 0;
+    for amount in time.split_whitespace() {
+        seconds += match (amount.strip_suffix('m'), amount.strip_suffix('s')) {
+            (Some(minutes), _) => minutes.parse::<u64>().ok()? * 60,
+            (_, Some(secs)) => secs.parse::<u64>().ok()?,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    use super::*;
+
+    /// Serves a single canned HTTP response on a local port, returning the
+    /// base URL to request and a handle that yields the request received.
+    fn serve(status: &str, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                    length = value.trim().parse().unwrap();
+                }
+                request += &line;
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            request += &String::from_utf8(body).unwrap();
+
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, server) = serve("200 OK", "1000\n2000\n");
+        let client = Client::new(&base_url, "abc123");
+
+        assert_eq!(client.fetch_input(2022, 1), Ok("1000\n2000\n".to_string()));
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("session=abc123"));
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (base_url, _) = serve(
+            "400 Bad Request",
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        );
+        let client = Client::new(&base_url, "expired");
+        assert_eq!(
+            client.fetch_input(2022, 1),
+            Err(ClientError::SessionExpired)
+        );
+
+        let (base_url, _) = serve(
+            "404 Not Found",
+            "Please don't repeatedly request this endpoint before it unlocks!",
+        );
+        let client = Client::new(&base_url, "abc123");
+        assert_eq!(
+            client.fetch_input(2022, 25),
+            Err(ClientError::NotUnlocked(25))
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let page = "<html><body><main><article><p>That's the right answer! \
+                    You are <em>one gold star</em> closer.</p></article></main></body></html>";
+        let (base_url, server) = serve("200 OK", page);
+        let client = Client::new(&base_url, "abc123");
+
+        assert_eq!(
+            client.submit(2022, 5, 2, &Answer::from("MCD")),
+            Ok(Outcome::Correct)
+        );
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/5/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("level=2&answer=MCD"));
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        let article = |text: &str| format!("<main>\n<article><p>{text}</p></article>\n</main>");
+
+        assert_eq!(
+            parse_outcome(&article(
+                "That's not the right answer; your answer is too high.  If you're stuck, \
+                 make sure you're using the full input data. <a href=\"/2022/day/1\">[Return]</a>"
+            )),
+            Outcome::Wrong(Hint::TooHigh)
+        );
+        assert_eq!(
+            parse_outcome(&article(
+                "That's not the right answer; your answer is too low.  Please wait one minute."
+            )),
+            Outcome::Wrong(Hint::TooLow)
+        );
+        assert_eq!(
+            parse_outcome(&article(
+                "That's not the right answer.  Please wait one minute."
+            )),
+            Outcome::Wrong(Hint::Wrong)
+        );
+        assert_eq!(
+            parse_outcome(&article(
+                "You gave an answer too recently; you have to wait after submitting an \
+                 answer before trying again.  You have 4m 31s left to wait."
+            )),
+            Outcome::Wait(Duration::from_secs(271))
+        );
+
+        // Unexpected amounts fall back to the default wait rather than panicking
+        assert_eq!(wait_time("You have 4é left to wait"), None);
+        assert_eq!(
+            parse_outcome(&article(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Outcome::WrongLevel
+        );
+        assert_eq!(
+            parse_outcome(&article("Something <em>new</em>")),
+            Outcome::Unknown("Something new".to_string())
+        );
+    }
+
+    #[test]
+    fn test_cache_input() {
+        let dir = std::env::temp_dir().join(format!("input-{}", std::process::id()));
+        let path = dir.join("1.txt");
+
+        let (base_url, _) = serve("200 OK", "1000\n");
+        let client = Client::new(&base_url, "abc123");
+        assert!(client.cache_input(2022, 1, &path).unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n");
+
+        // Nothing is listening any more, so this would fail if it made a request
+        assert!(!client.cache_input(2022, 1, &path).unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_missing_session() {
+        let path = std::env::temp_dir().join("no-such-session");
+        assert!(matches!(
+            Client::from_session_file(BASE_URL, &path),
+            Err(Error::Client(ClientError::MissingSession(_)))
+        ));
+    }
+}
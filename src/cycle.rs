@@ -0,0 +1,230 @@
+//! Skipping ahead in simulations that settle into a loop, such as the
+//! tower of rocks in Day 17, where the answer is wanted after far more
+//! steps than could be simulated one at a time.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+/// Where a simulation starts repeating itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<V> {
+    /// The first step of the part that repeats
+    pub start: usize,
+    /// How many steps each repeat takes
+    pub period: usize,
+    /// How much the value changes over each repeat
+    pub delta: V,
+}
+
+/// The value a simulation has after the target number of steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extrapolation<V> {
+    pub value: V,
+    /// The cycle that was skipped through, or `None` if the target was
+    /// reached before the simulation repeated itself
+    pub cycle: Option<Cycle<V>>,
+}
+
+/// Why a simulation couldn't be extrapolated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CycleError<E> {
+    /// A step of the simulation failed
+    Step(E),
+    /// The value after the target number of steps doesn't fit in its type
+    Overflow,
+}
+
+impl<E: fmt::Display> fmt::Display for CycleError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CycleError::Step(error) => write!(f, "{error}"),
+            CycleError::Overflow => write!(f, "the extrapolated value overflows"),
+        }
+    }
+}
+
+/// A value that changes by the same amount each time a cycle repeats, such
+/// as a height or a list of counts.
+pub trait Linear: Clone {
+    /// The change from `earlier` to `self`, or `None` if it can't be stored,
+    /// such as a fall in an unsigned value.
+    fn delta(&self, earlier: &Self) -> Option<Self>;
+
+    /// The value after changing by `delta` a number of `times`, or `None` if
+    /// it overflows.
+    fn add_times(&self, delta: &Self, times: usize) -> Option<Self>;
+}
+
+macro_rules! linear_int {
+    ($($int:ty),*) => {
+        $(
+            impl Linear for $int {
+                fn delta(&self, earlier: &Self) -> Option<Self> {
+                    self.checked_sub(*earlier)
+                }
+
+                fn add_times(&self, delta: &Self, times: usize) -> Option<Self> {
+                    let times = <$int>::try_from(times).ok()?;
+                    self.checked_add(delta.checked_mul(times)?)
+                }
+            }
+        )*
+    };
+}
+
+linear_int!(isize, i64, usize, u64);
+
+impl<T: Linear> Linear for Vec<T> {
+    fn delta(&self, earlier: &Self) -> Option<Self> {
+        self.iter().zip(earlier).map(|(a, b)| a.delta(b)).collect()
+    }
+
+    fn add_times(&self, delta: &Self, times: usize) -> Option<Self> {
+        self.iter()
+            .zip(delta)
+            .map(|(a, d)| a.add_times(d, times))
+            .collect()
+    }
+}
+
+/// Finds the value of a simulation after `target` steps. `state` describes
+/// the simulation as a key, which is the same whenever the simulation will
+/// go on to do the same thing, and the value being tracked. `step` moves the
+/// simulation on by one step.
+///
+/// Once a key is seen again, the steps between the two are repeated to skip
+/// ahead to the target, assuming the value changes by the same amount each
+/// time round. Fails if a step does, or if the value skipped ahead to
+/// overflows.
+pub fn extrapolate_with_cycle<S, K, V, E>(
+    mut simulation: S,
+    mut state: impl FnMut(&S) -> (K, V),
+    mut step: impl FnMut(&mut S) -> Result<(), E>,
+    target: usize,
+) -> Result<Extrapolation<V>, CycleError<E>>
+where
+    K: Eq + Hash,
+    V: Linear,
+{
+    let mut seen: HashMap<K, usize> = HashMap::new();
+    let mut values = Vec::new();
+
+    for i in 0..=target {
+        let (key, value) = state(&simulation);
+        values.push(value);
+        if let Some(&start) = seen.get(&key) {
+            let period = i - start;
+            let delta = values[i]
+                .delta(&values[start])
+                .ok_or(CycleError::Overflow)?;
+            let (repeats, rest) = ((target - start) / period, (target - start) % period);
+            return Ok(Extrapolation {
+                value: values[start + rest]
+                    .add_times(&delta, repeats)
+                    .ok_or(CycleError::Overflow)?,
+                cycle: Some(Cycle {
+                    start,
+                    period,
+                    delta,
+                }),
+            });
+        }
+        seen.insert(key, i);
+        if i < target {
+            step(&mut simulation).map_err(CycleError::Step)?;
+        }
+    }
+
+    Ok(Extrapolation {
+        value: values.swap_remove(target),
+        cycle: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use super::*;
+
+    /// Counts up forever, where the key only looks at the last digit.
+    fn counter(target: usize) -> Extrapolation<usize> {
+        let step = |n: &mut usize| -> Result<(), Infallible> {
+            *n += 1;
+            Ok(())
+        };
+        extrapolate_with_cycle(0, |&n| (n % 10, n), step, target).unwrap()
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let extrapolation = counter(1_000_000_000_007);
+        assert_eq!(extrapolation.value, 1_000_000_000_007);
+        assert_eq!(
+            extrapolation.cycle,
+            Some(Cycle {
+                start: 0,
+                period: 10,
+                delta: 10
+            })
+        );
+
+        // Reaching the target first doesn't need a cycle
+        assert_eq!(
+            counter(7),
+            Extrapolation {
+                value: 7,
+                cycle: None
+            }
+        );
+        assert_eq!(counter(0).value, 0);
+    }
+
+    #[test]
+    fn test_cycle_after_lead_in() {
+        // Doubling modulo 20 goes 1, 2, 4, 8, 16, 12, 4, ..., repeating from
+        // the third value, while counting even and odd values as it goes
+        let step = |(n, steps): &mut (usize, Vec<usize>)| -> Result<(), Infallible> {
+            *n = *n * 2 % 20;
+            steps[*n % 2] += 1;
+            Ok(())
+        };
+        let state = |(n, steps): &(usize, Vec<usize>)| (*n, steps.clone());
+        let extrapolation = extrapolate_with_cycle((1, vec![0, 0]), state, step, 1003).unwrap();
+
+        let cycle = extrapolation.cycle.unwrap();
+        assert_eq!((cycle.start, cycle.period), (2, 4));
+        assert_eq!(cycle.delta, vec![4, 0]);
+        assert_eq!(extrapolation.value, vec![1003, 0]);
+    }
+
+    #[test]
+    fn test_step_error() {
+        let step = |n: &mut usize| {
+            if *n == 3 {
+                return Err("stuck");
+            }
+            *n += 1;
+            Ok(())
+        };
+        assert_eq!(
+            extrapolate_with_cycle(0, |&n| (n, n), step, 10),
+            Err(CycleError::Step("stuck"))
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let step = |n: &mut i64| -> Result<(), Infallible> {
+            *n += 1;
+            Ok(())
+        };
+        let overflow = extrapolate_with_cycle(0, |&n| (n % 10, n), step, usize::MAX);
+        assert_eq!(overflow, Err(CycleError::Overflow));
+
+        // A value that falls can't be extrapolated without a sign
+        assert_eq!(5usize.delta(&7), None);
+        assert_eq!((-3isize).delta(&2), Some(-5));
+    }
+}
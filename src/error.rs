@@ -0,0 +1,224 @@
+use std::{fmt, io, path::PathBuf};
+
+/// An input line that couldn't be parsed into the model for a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number of the offending input
+    pub line: usize,
+    /// 1-based column of the line where parsing failed, if known
+    pub column: Option<usize>,
+    /// The offending input line
+    pub text: String,
+    /// What was wrong with the line
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, text: impl Into<String>, reason: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column: None,
+            text: text.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Points the error at a column of the line.
+    pub fn at_column(self, column: usize) -> Self {
+        ParseError {
+            column: Some(column),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.column {
+            Some(column) => write!(
+                f,
+                "line {}, column {column}: {}\n    | {}\n    | {:>column$}",
+                self.line, self.reason, self.text, "^"
+            ),
+            None => write!(
+                f,
+                "line {}: {}\n    | {}",
+                self.line, self.reason, self.text
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A parsed input for which a part couldn't be solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The input is well formed but breaks an assumption of the puzzle
+    InvalidInput(String),
+    /// The search for an answer came up empty
+    NoAnswer(String),
+    /// The part hasn't been solved yet
+    NotImplemented,
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::InvalidInput(reason) => write!(f, "invalid input: {reason}"),
+            SolveError::NoAnswer(reason) => write!(f, "no answer found: {reason}"),
+            SolveError::NotImplemented => write!(f, "not implemented yet"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// An override for a tunable constant of a day that couldn't be applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    /// The day has no parameter with this name, only the known ones
+    Unknown {
+        key: String,
+        known: Vec<&'static str>,
+    },
+    /// The value isn't valid for the type of the parameter
+    Invalid { key: String, value: String },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamError::Unknown { key, known } if known.is_empty() => {
+                write!(f, "unknown parameter `{key}`, there are no parameters")
+            }
+            ParamError::Unknown { key, known } => write!(
+                f,
+                "unknown parameter `{key}`, expected one of {}",
+                known.join(", ")
+            ),
+            ParamError::Invalid { key, value } => {
+                write!(f, "invalid value `{value}` for parameter `{key}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParamError {}
+
+/// A request to the Advent of Code site that didn't succeed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientError {
+    /// There is no session cookie in the given file
+    MissingSession(PathBuf),
+    /// The site didn't accept the session cookie, which has probably expired
+    SessionExpired,
+    /// The puzzle for the day hasn't been released yet
+    NotUnlocked(usize),
+    /// The site responded with a status that wasn't expected
+    Status { code: u16, body: String },
+    /// The site couldn't be reached
+    Network(String),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::MissingSession(path) => write!(
+                f,
+                "no session cookie in {}, copy it from the Advent of Code site",
+                path.display()
+            ),
+            ClientError::SessionExpired => write!(
+                f,
+                "the session cookie wasn't accepted, it has probably expired"
+            ),
+            ClientError::NotUnlocked(day) => write!(f, "day {day} hasn't been unlocked yet"),
+            ClientError::Status { code, body } => {
+                write!(f, "unexpected response with status {code}: {}", body.trim())
+            }
+            ClientError::Network(reason) => write!(f, "could not reach the site: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+/// Everything that can go wrong while solving a day.
+#[derive(Debug)]
+pub enum Error {
+    /// The input file couldn't be read
+    Io { path: PathBuf, source: io::Error },
+    /// There is no solution registered for the day
+    UnknownDay { year: usize, day: usize },
+    /// The input for the day couldn't be parsed
+    Parse { day: usize, source: ParseError },
+    /// A parameter given for the day couldn't be set
+    Param { day: usize, source: ParamError },
+    /// One of the parts for the day couldn't be solved
+    Solve {
+        day: usize,
+        part: u8,
+        source: SolveError,
+    },
+    /// A file of confirmed answers couldn't be read or written
+    Ledger {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    /// The manifest of examples couldn't be parsed
+    Manifest {
+        path: PathBuf,
+        source: Box<toml::de::Error>,
+    },
+    /// A request to the Advent of Code site failed
+    Client(ClientError),
+    /// A file that would have been created is already there
+    AlreadyExists(PathBuf),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => {
+                write!(f, "could not read {}: {source}", path.display())
+            }
+            Error::UnknownDay { year, day } => write!(f, "no solution for {year} day {day}"),
+            Error::Parse { day, source } => write!(f, "day {day}: invalid input on {source}"),
+            Error::Param { day, source } => write!(f, "day {day}: {source}"),
+            Error::Solve { day, part, source } => write!(f, "day {day} part {part}: {source}"),
+            Error::Ledger { path, source } => {
+                write!(f, "invalid answers in {}: {source}", path.display())
+            }
+            Error::Manifest { path, source } => {
+                write!(f, "invalid manifest {}: {source}", path.display())
+            }
+            Error::Client(source) => write!(f, "{source}"),
+            Error::AlreadyExists(path) => {
+                write!(f, "{} already exists, not replacing it", path.display())
+            }
+        }
+    }
+}
+
+impl From<ClientError> for Error {
+    fn from(source: ClientError) -> Self {
+        Error::Client(source)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::UnknownDay { .. } => None,
+            Error::Parse { source, .. } => Some(source),
+            Error::Param { source, .. } => Some(source),
+            Error::Solve { source, .. } => Some(source),
+            Error::Ledger { source, .. } => Some(source),
+            Error::Manifest { source, .. } => Some(source),
+            Error::Client(source) => Some(source),
+            Error::AlreadyExists(_) => None,
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::error::Error;
+use crate::input::{load_input, InputSource};
+use crate::runner::{get_solution, solve_part};
+use crate::utils::Answer;
+
+/// The manifest of the examples each day is tested against.
+pub const MANIFEST: &str = "tests/examples.toml";
+
+/// The examples listed in a manifest, as `[[example]]` tables.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+pub struct Manifest {
+    #[serde(default, rename = "example")]
+    pub examples: Vec<Example>,
+}
+
+/// An example input from a puzzle's text, with the answers the puzzle gives
+/// for it. Parts without an answer aren't checked.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Example {
+    pub year: usize,
+    pub day: usize,
+    /// The input file, which defaults to `.test_input/{year}/{day}.txt`
+    pub input: Option<PathBuf>,
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+    /// Parameters to override for the example, such as the row Day 15 searches
+    #[serde(default)]
+    pub params: BTreeMap<String, toml::Value>,
+}
+
+impl Manifest {
+    /// Loads the examples listed in a manifest.
+    pub fn load(path: impl AsRef<Path>) -> Result<Manifest, Error> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        toml::from_str(&contents).map_err(|source| Error::Manifest {
+            path: path.to_path_buf(),
+            source: Box::new(source),
+        })
+    }
+}
+
+impl Example {
+    /// The file holding the example input.
+    pub fn path(&self) -> PathBuf {
+        match &self.input {
+            Some(path) => path.clone(),
+            None => InputSource::Example.path(self.year, self.day),
+        }
+    }
+
+    /// A name for the example such as `year2022::day14`, followed by the
+    /// name of its input file when it isn't the default, as in
+    /// `year2022::day9::9a`.
+    pub fn name(&self) -> String {
+        let day = format!("year{}::day{}", self.year, self.day);
+        match self.input.as_deref().and_then(Path::file_stem) {
+            Some(file) => format!("{day}::{}", file.to_string_lossy()),
+            None => day,
+        }
+    }
+
+    /// The overridden parameters as `(key, value)` pairs, as they would be
+    /// given on the command line.
+    pub fn params(&self) -> Vec<(String, String)> {
+        self.params
+            .iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    toml::Value::String(value) => value.clone(),
+                    value => value.to_string(),
+                };
+                (key.clone(), value)
+            })
+            .collect()
+    }
+
+    /// Whether the example has no answers to check yet, like the entry `new`
+    /// adds for a new day.
+    pub fn is_pending(&self) -> bool {
+        self.part_one.is_none() && self.part_two.is_none()
+    }
+
+    /// Solves the example, describing each part whose answer isn't the
+    /// expected one. Pending examples pass without being solved, as their
+    /// input may still be empty.
+    pub fn check(&self) -> Result<(), String> {
+        if self.is_pending() {
+            return Ok(());
+        }
+        let solution = load_input(self.path())
+            .and_then(|input| get_solution(self.year, self.day, input, &self.params()))
+            .map_err(|error| error.to_string())?;
+
+        let mut failures = Vec::new();
+        for (part, expected) in [(1, &self.part_one), (2, &self.part_two)] {
+            let Some(expected) = expected else {
+                continue;
+            };
+            match solve_part(self.day, part, solution.as_ref(), None).answer {
+                Ok(answer) if answer == *expected => (),
+                Ok(answer) => failures.push(mismatch(part, expected, &answer)),
+                Err(error) => failures.push(error.to_string()),
+            }
+        }
+
+        match failures.is_empty() {
+            true => Ok(()),
+            false => Err(failures.join("\n")),
+        }
+    }
+}
+
+/// Describes a wrong answer, starting multi-line answers on their own line.
+fn mismatch(part: u8, expected: &Answer, answer: &Answer) -> String {
+    let separator = match expected.is_multiline() || answer.is_multiline() {
+        true => "\n",
+        false => " ",
+    };
+    format!("part {part}: expected{separator}{expected}{separator}but found{separator}{answer}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest: Manifest = toml::from_str(
+            r#"
+            [[example]]
+            year = 2022
+            day = 9
+            input = ".test_input/2022/9b.txt"
+            part_two = 36
+
+            [[example]]
+            year = 2022
+            day = 15
+            part_one = 26
+            params = { y_target = 10, mode = "fast" }
+            "#,
+        )
+        .unwrap();
+
+        let [larger, sensors] = &manifest.examples[..] else {
+            panic!("expected two examples");
+        };
+        assert_eq!(larger.path(), Path::new(".test_input/2022/9b.txt"));
+        assert_eq!(larger.name(), "year2022::day9::9b");
+        assert_eq!(larger.part_one, None);
+        assert_eq!(larger.part_two, Some(Answer::Int(36)));
+
+        assert_eq!(sensors.path(), Path::new(".test_input/2022/15.txt"));
+        assert_eq!(sensors.name(), "year2022::day15");
+        assert_eq!(
+            sensors.params(),
+            [
+                ("mode".to_string(), "fast".to_string()),
+                ("y_target".to_string(), "10".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pending() {
+        let manifest: Manifest =
+            toml::from_str("[[example]]\nyear = 2022\nday = 19\ninput = \"missing.txt\"\n")
+                .unwrap();
+        let example = &manifest.examples[0];
+        assert!(example.is_pending());
+        assert_eq!(example.check(), Ok(()));
+    }
+
+    #[test]
+    fn test_unknown_field() {
+        let manifest = toml::from_str::<Manifest>("[[example]]\nyear = 2022\nday = 1\npart = 1\n");
+        assert!(manifest.is_err());
+    }
+
+    #[test]
+    fn test_mismatch() {
+        assert_eq!(
+            mismatch(1, &Answer::Int(24000), &Answer::Int(45000)),
+            "part 1: expected 24000 but found 45000"
+        );
+        assert_eq!(
+            mismatch(2, &Answer::from("#.\n.#"), &Answer::Int(0)),
+            "part 2: expected\n#.\n.#\nbut found\n0"
+        );
+    }
+}
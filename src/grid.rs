@@ -0,0 +1,341 @@
+//! A rectangular grid of cells, as drawn by the inputs of days such as Day 8
+//! and Day 12. Positions are `(x, y)` pairs, with `(0, 0)` at the top left
+//! and `y` growing downwards.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::ParseError;
+
+/// The steps to the four neighbours of a cell: up, right, down and left.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The steps to all eight neighbours of a cell, including diagonals.
+pub const ALL_DIRECTIONS: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Like [`Grid::new`], but `None` if there isn't room for the cells.
+    pub fn try_new(width: usize, height: usize, value: T) -> Option<Self> {
+        let size = width.checked_mul(height)?;
+        let mut cells = Vec::new();
+        cells.try_reserve_exact(size).ok()?;
+        cells.resize(size, value);
+        Some(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Swaps the rows and columns of the grid.
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    /// The rows of the grid as separate vectors.
+    pub fn to_rows(&self) -> Vec<Vec<T>> {
+        self.rows().map(<[T]>::to_vec).collect()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a grid from lines of characters, one cell per character.
+    /// `cell` reads each character, and a character it rejects is an error
+    /// pointing at its column, with `expected` as the reason.
+    pub fn parse(
+        input: impl Iterator<Item = String>,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (i, line) in input.enumerate() {
+            for (column, c) in line.chars().enumerate() {
+                let value = cell(c)
+                    .ok_or_else(|| ParseError::new(i + 1, &line, expected).at_column(column + 1))?;
+                cells.push(value);
+            }
+            let length = line.chars().count();
+            if length == 0 || width.is_some_and(|width| width != length) {
+                return Err(ParseError::new(
+                    i + 1,
+                    line,
+                    "expected rows of equal length",
+                ));
+            }
+            width = Some(length);
+            height += 1;
+        }
+
+        match width {
+            Some(width) => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            None => Err(ParseError::new(1, "", "expected a grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        match x < self.width && y < self.height {
+            true => Some(&self.cells[y * self.width + x]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        match x < self.width && y < self.height {
+            true => Some(&mut self.cells[y * self.width + x]),
+            false => None,
+        }
+    }
+
+    /// The position one `step` away from `position`, if it's in the grid.
+    pub fn step(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+        Some((x, y))
+    }
+
+    /// The up to four positions next to `position`, not counting diagonals.
+    pub fn neighbours(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |step| self.step(position, step))
+    }
+
+    /// The up to eight positions around `position`, including diagonals.
+    pub fn all_neighbours(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ALL_DIRECTIONS
+            .into_iter()
+            .filter_map(move |step| self.step(position, step))
+    }
+
+    /// The positions from `position` towards the edge of the grid, taking
+    /// `step` each time, not including `position` itself.
+    pub fn ray(
+        &self,
+        position: (usize, usize),
+        step: (isize, isize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        std::iter::successors(self.step(position, step), move |&next| {
+            self.step(next, step)
+        })
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every position in the grid with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells[x..].iter().step_by(self.width).take(self.height)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The position of the first cell equal to `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    /// The positions of every cell equal to `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    /// A grid of the same size with `f` applied to each cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "{position:?} is outside a {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside a {width}x{height} grid"))
+    }
+}
+
+/// Draws the grid one row per line.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(rows: &[&str]) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(
+            rows.iter().map(|row| row.to_string()),
+            |c| c.to_digit(10),
+            "expected a digit",
+        )
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits(&["123", "456"]).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        let error = digits(&["123", "4x6"]).unwrap_err();
+        assert_eq!((error.line, error.column), (2, Some(2)));
+        assert_eq!(digits(&["123", "45"]).unwrap_err().line, 2);
+        assert!(digits(&[]).is_err());
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(Grid::try_new(3, 2, 7).unwrap(), Grid::new(3, 2, 7));
+        assert!(Grid::try_new(usize::MAX, 2, 0u8).is_none());
+        assert!(Grid::try_new(usize::MAX / 2, 1, 0u8).is_none());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours((1, 1)).count(), 4);
+        assert_eq!(
+            grid.all_neighbours((2, 0)).collect::<Vec<_>>(),
+            [(2, 1), (1, 1), (1, 0)]
+        );
+        assert_eq!(grid.all_neighbours((1, 1)).count(), 8);
+        assert_eq!(grid.step((0, 2), (-1, 0)), None);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = digits(&["123", "456"]).unwrap();
+        assert_eq!(grid.row(1).collect::<Vec<_>>(), [&4, &5, &6]);
+        assert_eq!(grid.column(1).collect::<Vec<_>>(), [&2, &5]);
+        assert_eq!(
+            grid.ray((0, 0), (1, 0)).collect::<Vec<_>>(),
+            [(1, 0), (2, 0)]
+        );
+        assert_eq!(grid.ray((2, 1), (1, 1)).count(), 0);
+
+        let transposed = grid.transpose();
+        assert_eq!(transposed.to_string(), "14\n25\n36");
+        assert_eq!(transposed.transpose(), grid);
+    }
+
+    #[test]
+    fn test_find() {
+        let mut grid = digits(&["121", "212"]).unwrap();
+        assert_eq!(grid.find(&2), Some((1, 0)));
+        assert_eq!(
+            grid.find_all(&1).collect::<Vec<_>>(),
+            [(0, 0), (2, 0), (1, 1)]
+        );
+        assert_eq!(grid.find(&3), None);
+
+        grid[(1, 0)] = 3;
+        assert_eq!(grid.find(&3), Some((1, 0)));
+        assert_eq!(grid.map(|&n| n * 2).to_string(), "262\n424");
+    }
+}
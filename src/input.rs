@@ -0,0 +1,69 @@
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::{fs, vec};
+
+use crate::error::Error;
+
+/// Where to read the input for a day from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input in `.input/{year}/{day}.txt`
+    Puzzle,
+    /// The example from the puzzle text in `.test_input/{year}/{day}.txt`
+    Example,
+    /// A file given on the command line, where `-` is stdin
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// The path to read the input for a day from.
+    pub fn path(&self, year: usize, day: usize) -> PathBuf {
+        match self {
+            InputSource::Puzzle => format!(".input/{year}/{day}.txt").into(),
+            InputSource::Example => format!(".test_input/{year}/{day}.txt").into(),
+            InputSource::File(path) => path.clone(),
+        }
+    }
+}
+
+/// Reads the input for a day from a file, or from stdin when the path is `-`.
+pub fn load_input(file_path: impl AsRef<Path>) -> Result<vec::IntoIter<String>, Error> {
+    let file_path = file_path.as_ref();
+    let io_error = |source| Error::Io {
+        path: file_path.to_path_buf(),
+        source,
+    };
+
+    let lines = if file_path == Path::new("-") {
+        io::stdin()
+            .lock()
+            .lines()
+            .collect::<Result<Vec<String>, _>>()
+    } else {
+        let file = fs::OpenOptions::new()
+            .read(true)
+            .open(file_path)
+            .map_err(io_error)?;
+
+        let buffered_file = BufReader::new(file);
+        buffered_file.lines().collect::<Result<Vec<String>, _>>()
+    };
+
+    Ok(lines.map_err(io_error)?.into_iter())
+}
+
+/// Loads the input for a day, or a reason to skip the day when its input is missing.
+pub fn load_day_input(
+    year: usize,
+    day: usize,
+    source: &InputSource,
+) -> Result<Result<vec::IntoIter<String>, String>, Error> {
+    let file_path = source.path(year, day);
+    match load_input(&file_path) {
+        Ok(input) => Ok(Ok(input)),
+        Err(Error::Io { source, .. }) if source.kind() == io::ErrorKind::NotFound => {
+            Ok(Err(format!("no input at {}", file_path.display())))
+        }
+        Err(error) => Err(error),
+    }
+}
@@ -0,0 +1,223 @@
+//! Sets of integers kept as the ranges they cover, for puzzles about which
+//! parts of a line are covered, such as the sections in Day 4 or the
+//! positions a sensor can see in Day 15.
+
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// A set of integers stored as sorted, non-overlapping ranges. Ranges that
+/// overlap or touch are merged, so `1..=3` and `4..=6` are kept as `1..=6`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    /// The start and end of each range, both included
+    ranges: Vec<(isize, isize)>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    /// Adds every number in `range`, merging it with the ranges it touches.
+    pub fn insert(&mut self, range: RangeInclusive<isize>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self
+            .ranges
+            .partition_point(|&(_, e)| e.saturating_add(1) < start);
+        let last = self
+            .ranges
+            .partition_point(|&(s, _)| s <= end.saturating_add(1));
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    /// Removes every number in `range`, splitting the range it's in if need be.
+    pub fn remove(&mut self, range: RangeInclusive<isize>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self.ranges.partition_point(|&(_, e)| e < start);
+        let last = self.ranges.partition_point(|&(s, _)| s <= end);
+        if first == last {
+            return;
+        }
+        let before = (self.ranges[first].0, start - 1);
+        let after = (end + 1, self.ranges[last - 1].1);
+        let kept = [before, after].into_iter().filter(|&(s, e)| s <= e);
+        self.ranges.splice(first..last, kept);
+    }
+
+    /// How many numbers are in the set.
+    pub fn len(&self) -> usize {
+        self.ranges.iter().map(|&(s, e)| e.abs_diff(s) + 1).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: isize) -> bool {
+        self.range_from(value).is_some_and(|(s, _)| s <= value)
+    }
+
+    /// Whether every number in `range` is in the set.
+    pub fn contains_range(&self, range: RangeInclusive<isize>) -> bool {
+        let (start, end) = range.into_inner();
+        start > end
+            || self
+                .range_from(start)
+                .is_some_and(|(s, e)| s <= start && end <= e)
+    }
+
+    /// Whether any number in `range` is in the set.
+    pub fn overlaps(&self, range: RangeInclusive<isize>) -> bool {
+        let (start, end) = range.into_inner();
+        start <= end && self.range_from(start).is_some_and(|(s, _)| s <= end)
+    }
+
+    /// The numbers in `bounds` that aren't in the set.
+    pub fn gaps(&self, bounds: RangeInclusive<isize>) -> IntervalSet {
+        let mut gaps = IntervalSet::from(bounds);
+        for range in self.iter() {
+            gaps.remove(range);
+        }
+        gaps
+    }
+
+    /// The numbers in either set.
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        for range in other.iter() {
+            union.insert(range);
+        }
+        union
+    }
+
+    /// The numbers in both sets.
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&(s1, e1)), Some(&(s2, e2))) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (s1.max(s2), e1.min(e2));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            match e1 < e2 {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// The ranges making up the set, in order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<isize>> + '_ {
+        self.ranges.iter().map(|&(s, e)| s..=e)
+    }
+
+    /// The first range that doesn't end before `value`.
+    fn range_from(&self, value: isize) -> Option<(isize, isize)> {
+        let i = self.ranges.partition_point(|&(_, e)| e < value);
+        self.ranges.get(i).copied()
+    }
+}
+
+impl From<RangeInclusive<isize>> for IntervalSet {
+    fn from(range: RangeInclusive<isize>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl FromIterator<RangeInclusive<isize>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<isize>>>(ranges: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/// Writes the set like `{1..=3, 7..=7}`.
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, (s, e)) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{s}..={e}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut set = IntervalSet::new();
+        set.insert(5..=8);
+        set.insert(1..=2);
+        set.insert(12..=12);
+        assert_eq!(set.to_string(), "{1..=2, 5..=8, 12..=12}");
+        assert_eq!(set.len(), 7);
+
+        // Touching ranges are merged as well as overlapping ones
+        set.insert(3..=4);
+        set.insert(7..=11);
+        assert_eq!(set.to_string(), "{1..=12}");
+
+        #[allow(clippy::reversed_empty_ranges)]
+        set.insert(20..=19);
+        assert_eq!(set.len(), 12);
+        assert!(IntervalSet::new().is_empty());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set = IntervalSet::from(1..=10);
+        set.remove(4..=6);
+        assert_eq!(set.to_string(), "{1..=3, 7..=10}");
+        set.remove(3..=7);
+        assert_eq!(set.to_string(), "{1..=2, 8..=10}");
+        set.remove(-5..=1);
+        set.remove(10..=10);
+        assert_eq!(set.to_string(), "{2..=2, 8..=9}");
+        set.remove(0..=20);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_queries() {
+        let set: IntervalSet = [2..=4, 8..=10].into_iter().collect();
+        assert!(set.contains(3) && set.contains(8));
+        assert!(!set.contains(5) && !set.contains(11));
+        assert!(set.contains_range(8..=9));
+        assert!(!set.contains_range(3..=8));
+        assert!(set.overlaps(4..=7));
+        assert!(!set.overlaps(5..=7));
+        assert_eq!(set.gaps(0..=12).to_string(), "{0..=1, 5..=7, 11..=12}");
+        assert_eq!(set.gaps(3..=4).len(), 0);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: IntervalSet = [1..=5, 10..=15].into_iter().collect();
+        let b: IntervalSet = [4..=11, 15..=20].into_iter().collect();
+        assert_eq!(a.union(&b).to_string(), "{1..=20}");
+        assert_eq!(a.intersection(&b).to_string(), "{4..=5, 10..=11, 15..=15}");
+        assert!(a.intersection(&IntervalSet::new()).is_empty());
+    }
+}
@@ -0,0 +1,228 @@
+use std::path::{Path, PathBuf};
+use std::{fmt, fs, io};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::utils::Answer;
+
+/// Where the confirmed answers for each day are kept, in a directory for each year.
+pub const DIR: &str = ".answers";
+
+/// The directory holding the answers for the days of a year.
+pub fn dir(year: usize) -> PathBuf {
+    Path::new(DIR).join(year.to_string())
+}
+
+/// The confirmed answers to the puzzle input for a day, along with the
+/// guesses the site rejected, stored as JSON in `.answers/{year}/{day}.json`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rejected_one: Vec<Rejected>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rejected_two: Vec<Rejected>,
+}
+
+/// A guess the site said was wrong.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rejected {
+    pub answer: Answer,
+    pub hint: Hint,
+}
+
+/// What the site said about a wrong guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+    /// The guess was wrong without saying which way
+    Wrong,
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+            Hint::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+/// How an answer compares to the confirmed answer for its part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer matches the confirmed answer
+    Pass,
+    /// The answer differs from the confirmed answer, which is kept here
+    Fail(Answer),
+    /// There is no confirmed answer yet
+    New,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail(_) => write!(f, "FAIL"),
+            Verdict::New => write!(f, "NEW"),
+        }
+    }
+}
+
+impl Ledger {
+    /// The file holding the answers for a day.
+    pub fn path(dir: &Path, day: usize) -> PathBuf {
+        dir.join(format!("{day}.json"))
+    }
+
+    /// Loads the answers for a day, which are empty if none have been recorded.
+    pub fn load(dir: &Path, day: usize) -> Result<Ledger, Error> {
+        let path = Ledger::path(dir, day);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(source) if source.kind() == io::ErrorKind::NotFound => return Ok(Ledger::default()),
+            Err(source) => return Err(Error::Io { path, source }),
+        };
+        serde_json::from_str(&contents).map_err(|source| Error::Ledger { path, source })
+    }
+
+    /// Saves the answers for a day, creating the directory if needed.
+    pub fn save(&self, dir: &Path, day: usize) -> Result<(), Error> {
+        let path = Ledger::path(dir, day);
+        let json = serde_json::to_string_pretty(self).map_err(|source| Error::Ledger {
+            path: path.clone(),
+            source,
+        })?;
+        fs::create_dir_all(dir)
+            .and_then(|()| fs::write(&path, json + "\n"))
+            .map_err(|source| Error::Io { path, source })
+    }
+
+    /// The confirmed answer for a part, if there is one.
+    pub fn confirmed(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part_one.as_ref(),
+            _ => self.part_two.as_ref(),
+        }
+    }
+
+    /// Records the answer for a part as confirmed, replacing any earlier answer.
+    pub fn record(&mut self, part: u8, answer: Answer) {
+        match part {
+            1 => self.part_one = Some(answer),
+            _ => self.part_two = Some(answer),
+        }
+    }
+
+    /// The guesses for a part that the site rejected.
+    pub fn rejected(&self, part: u8) -> &[Rejected] {
+        match part {
+            1 => &self.rejected_one,
+            _ => &self.rejected_two,
+        }
+    }
+
+    /// Records a guess for a part that the site rejected.
+    pub fn reject(&mut self, part: u8, answer: Answer, hint: Hint) {
+        let rejected = match part {
+            1 => &mut self.rejected_one,
+            _ => &mut self.rejected_two,
+        };
+        rejected.push(Rejected { answer, hint });
+    }
+
+    /// Finds a rejected guess showing an answer is wrong, either because it
+    /// was guessed before or because it's beyond a guess that was too high
+    /// or too low.
+    pub fn known_wrong(&self, part: u8, answer: &Answer) -> Option<&Rejected> {
+        self.rejected(part).iter().find(|rejected| {
+            match (rejected.hint, &rejected.answer, answer) {
+                _ if rejected.answer == *answer => true,
+                (Hint::TooHigh, Answer::Int(guess), Answer::Int(n)) => n >= guess,
+                (Hint::TooLow, Answer::Int(guess), Answer::Int(n)) => n <= guess,
+                _ => false,
+            }
+        })
+    }
+
+    /// Compares an answer to the confirmed answer for its part.
+    pub fn check(&self, part: u8, answer: &Answer) -> Verdict {
+        match self.confirmed(part) {
+            None => Verdict::New,
+            Some(confirmed) if confirmed == answer => Verdict::Pass,
+            Some(confirmed) => Verdict::Fail(confirmed.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let mut ledger = Ledger::default();
+        assert_eq!(ledger.check(1, &Answer::Int(24000)), Verdict::New);
+
+        ledger.record(1, Answer::Int(24000));
+        assert_eq!(ledger.check(1, &Answer::Int(24000)), Verdict::Pass);
+        assert_eq!(
+            ledger.check(1, &Answer::Int(45000)),
+            Verdict::Fail(Answer::Int(24000))
+        );
+        assert_eq!(ledger.check(2, &Answer::Int(24000)), Verdict::New);
+    }
+
+    #[test]
+    fn test_known_wrong() {
+        let mut ledger = Ledger::default();
+        ledger.reject(2, Answer::Int(5000), Hint::TooHigh);
+        ledger.reject(2, Answer::Int(1000), Hint::TooLow);
+        ledger.reject(2, Answer::from("ABC"), Hint::Wrong);
+
+        assert_eq!(ledger.known_wrong(1, &Answer::Int(5000)), None);
+        assert_eq!(
+            ledger.known_wrong(2, &Answer::Int(6000)),
+            Some(&ledger.rejected_two[0])
+        );
+        assert_eq!(
+            ledger.known_wrong(2, &Answer::Int(1000)),
+            Some(&ledger.rejected_two[1])
+        );
+        assert_eq!(
+            ledger.known_wrong(2, &Answer::from("ABC")),
+            Some(&ledger.rejected_two[2])
+        );
+        assert_eq!(ledger.known_wrong(2, &Answer::Int(3000)), None);
+    }
+
+    #[test]
+    fn test_load_and_save() {
+        let dir = std::env::temp_dir().join(format!("answers-{}", std::process::id()));
+        assert_eq!(Ledger::load(&dir, 5).unwrap(), Ledger::default());
+
+        let mut ledger = Ledger {
+            part_one: Some(Answer::from("CMZ")),
+            ..Ledger::default()
+        };
+        ledger.save(&dir, 5).unwrap();
+        let contents = fs::read_to_string(Ledger::path(&dir, 5)).unwrap();
+        assert_eq!(contents, "{\n  \"part_one\": \"CMZ\"\n}\n");
+        assert_eq!(Ledger::load(&dir, 5).unwrap(), ledger);
+
+        ledger.reject(2, Answer::from("MCZ"), Hint::Wrong);
+        ledger.save(&dir, 5).unwrap();
+        assert_eq!(Ledger::load(&dir, 5).unwrap(), ledger);
+
+        fs::write(Ledger::path(&dir, 5), "{ \"part_one\": ").unwrap();
+        assert!(matches!(Ledger::load(&dir, 5), Err(Error::Ledger { .. })));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,32 @@
+//! Solutions for Advent of Code, with everything needed to load the inputs,
+//! solve each day and check or report the answers. The days of each year
+//! are in a `yearYYYY` module, such as `year2022::day14`.
+
+#[macro_use]
+pub mod registry;
+#[macro_use]
+pub mod params;
+
+pub mod answer;
+pub mod bench;
+pub mod client;
+pub mod cycle;
+pub mod error;
+pub mod examples;
+pub mod grid;
+pub mod input;
+pub mod intervals;
+pub mod ledger;
+pub mod ocr;
+pub mod parsers;
+pub mod point;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod summary;
+pub mod utils;
+
+pub use input::load_input;
+
+// Declares a module for each `src/yearYYYY/` with a module for each `dayN.rs`
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
@@ -1,109 +1,486 @@
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod utils;
-
-use std::io::{BufRead, BufReader};
-use std::{env, fs};
-
-use day1::Day1;
-use day2::Day2;
-use day3::Day3;
-use day4::Day4;
-use day5::Day5;
-use day6::Day6;
-use day7::Day7;
-use day8::Day8;
-use day9::Day9;
-use day10::Day10;
-use day11::Day11;
-use day12::Day12;
-use day13::Day13;
-use day14::Day14;
-use day15::Day15;
-use day16::Day16;
-use day17::Day17;
-use day18::Day18;
-use utils::{time_execution, Solution};
-
-use crate::utils::FromInput;
-
-/// Reads the day from the arguments
-fn get_day_from_args() -> usize {
-    env::args()
-        .nth(1)
-        .expect("Must provide a day to solve")
-        .parse::<usize>()
-        .expect("Provided day wasn't a valid integer")
+mod cli;
+
+use std::env;
+use std::path::Path;
+use std::process::ExitCode;
+
+use adventofcode2022::bench::{format_duration, measure, Settings, Stats};
+use adventofcode2022::client::{self, Client, Outcome};
+use adventofcode2022::error::{Error, SolveError};
+use adventofcode2022::examples;
+use adventofcode2022::input::{load_day_input, load_input, InputSource};
+use adventofcode2022::ledger::{self, Ledger, Verdict};
+use adventofcode2022::registry::{self, DAYS};
+use adventofcode2022::runner::{get_solution, load_ledger, parts, run_day, set_params, solve_part};
+use adventofcode2022::scaffold;
+use adventofcode2022::summary::{
+    print_csv, print_json, print_summary, print_table, DayRun, PartRun,
+};
+use cli::{Command, Format, USAGE};
+
+/// Prints the usage along with the days that have a solution.
+fn print_help() {
+    let mut years: Vec<usize> = DAYS.iter().map(|registration| registration.year).collect();
+    years.dedup();
+
+    println!("{USAGE}");
+    println!();
+    for year in years {
+        let days: Vec<usize> = registry::days(year).map(|r| r.day).collect();
+        let (first, last) = (days[0], days[days.len() - 1]);
+        println!(
+            "Solutions are available for {year} days {first} to {last}, see `list` for details."
+        );
+    }
+}
+
+/// Prints every day with a solution and its title.
+fn print_list() {
+    for registration in DAYS {
+        println!(
+            "{}  {:>3}  {}",
+            registration.year, registration.day, registration.title
+        );
+    }
 }
 
-/// Reads the input for a day from the `.input` directory.
-fn load_input(file_path: String) -> impl Iterator<Item = String> {
-    let file = fs::OpenOptions::new()
-        .read(true)
-        .open(file_path)
-        .expect("File should be present");
+/// Solves several days, reporting progress on stderr.
+fn solve_days(
+    year: usize,
+    days: &[usize],
+    source: &InputSource,
+    part: Option<u8>,
+    params: &[(String, String)],
+) -> Vec<(usize, DayRun)> {
+    days.iter()
+        .map(|&day| {
+            eprintln!("Solving {year} day {day}...");
+            (day, run_day(year, day, source, part, params))
+        })
+        .collect()
+}
 
-    let buffered_file = BufReader::new(file);
+/// Solves several days, printing a summary table of the results or the
+/// result of each part in a machine-readable format.
+fn run_all(
+    year: usize,
+    days: &[usize],
+    part: Option<u8>,
+    params: &[(String, String)],
+    source: &InputSource,
+    format: Format,
+) -> ExitCode {
+    let runs = solve_days(year, days, source, part, params);
+    match format {
+        Format::Text => print_summary(&runs),
+        Format::Json => print_json(year, &runs),
+        Format::Csv => print_csv(year, &runs),
+    }
+    if format != Format::Text {
+        for (day, run) in runs.iter() {
+            if let DayRun::Skipped(reason) = run {
+                eprintln!("Skipped day {day}: {reason}");
+            }
+        }
+    }
 
-    buffered_file
-        .lines()
-        .map(|line| line.expect("Should parse valid line").to_string())
+    match runs.iter().any(|(_, run)| run.is_failure()) {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
 }
 
-///Get the solution for a specific day
-fn get_solution(day: usize, input: impl Iterator<Item = String>) -> Box<dyn Solution> {
-    match day {
-        1 => Box::new(Day1::from_input(input)),
-        2 => Box::new(Day2::from_input(input)),
-        3 => Box::new(Day3::from_input(input)),
-        4 => Box::new(Day4::from_input(input)),
-        5 => Box::new(Day5::from_input(input)),
-        6 => Box::new(Day6::from_input(input)),
-        7 => Box::new(Day7::from_input(input)),
-        8 => Box::new(Day8::from_input(input)),
-        9 => Box::new(Day9::from_input(input)),
-        10 => Box::new(Day10::from_input(input)),
-        11 => Box::new(Day11::from_input(input)),
-        12 => Box::new(Day12::from_input(input)),
-        13 => Box::new(Day13::from_input(input)),
-        14 => Box::new(Day14::from_input(input)),
-        15 => Box::new(Day15::from_input(input)),
-        16 => Box::new(Day16::from_input(input)),
-        17 => Box::new(Day17::from_input(input)),
-        18 => Box::new(Day18::from_input(input)),
-        _other => panic!("No solution for day {day}"),
+/// Solves several days and records their answers as confirmed.
+fn record(year: usize, days: &[usize]) -> ExitCode {
+    let dir = ledger::dir(year);
+    let runs = solve_days(year, days, &InputSource::Puzzle, None, &[]);
+    print_summary(&runs);
+
+    let mut recorded = 0;
+    let mut failed = runs.iter().any(|(_, run)| run.is_failure());
+    for (day, run) in runs.iter() {
+        if !matches!(run, DayRun::Solved { .. }) {
+            continue;
+        }
+        let result = Ledger::load(&dir, *day).and_then(|mut ledger| {
+            for (part, run) in run.parts() {
+                if let Ok(answer) = &run.answer {
+                    ledger.record(part, answer.clone());
+                    recorded += 1;
+                }
+            }
+            ledger.save(&dir, *day)
+        });
+        if let Err(error) = result {
+            println!();
+            println!("error: {error}");
+            failed = true;
+        }
+    }
+
+    println!();
+    println!("Recorded {recorded} answers in {}", dir.display());
+    match failed {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
     }
 }
 
-fn main() {
-    let day = get_day_from_args();
-    let file_path = format!(".input/{day}.txt");
-    let input = load_input(file_path);
-    let solution = get_solution(day, input);
+/// Solves several days, failing if any answer differs from the confirmed one.
+fn verify(year: usize, days: &[usize]) -> ExitCode {
+    let runs = solve_days(year, days, &InputSource::Puzzle, None, &[]);
+    print_summary(&runs);
 
-    println!("Solving day {day}...");
-    println!("==================================================");
+    match runs
+        .iter()
+        .any(|(_, run)| run.is_failure() || run.is_regression())
+    {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+/// A row of the benchmark table for one stage of a day.
+fn bench_row(day: usize, stage: &str, stats: &Stats) -> Vec<String> {
+    vec![
+        day.to_string(),
+        stage.to_string(),
+        stats.runs.to_string(),
+        format_duration(stats.min),
+        format_duration(stats.median),
+        format_duration(stats.mean),
+        format_duration(stats.stddev),
+    ]
+}
+
+/// Benchmarks parsing the input and solving each part of several days, or
+/// only the given part, printing a table of timings. Parse times include
+/// copying the input lines.
+fn run_bench(
+    year: usize,
+    days: &[usize],
+    part: Option<u8>,
+    params: &[(String, String)],
+    source: &InputSource,
+    settings: &Settings,
+) -> ExitCode {
+    let headers = ["Day", "Stage", "Runs", "Min", "Median", "Mean", "Std dev"];
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+
+    for &day in days {
+        let lines: Vec<String> = match load_day_input(year, day, source) {
+            Ok(Ok(input)) => input.collect(),
+            Ok(Err(reason)) => {
+                rows.push(vec![day.to_string(), format!("skipped: {reason}")]);
+                continue;
+            }
+            Err(error) => {
+                rows.push(vec![day.to_string(), "error".to_string()]);
+                errors.push(error);
+                continue;
+            }
+        };
+        let Some(registration) = registry::find(year, day) else {
+            rows.push(vec![day.to_string(), "skipped: no solution".to_string()]);
+            continue;
+        };
+
+        eprintln!("Benchmarking {year} day {day}...");
+        let parse = || (registration.build)(Box::new(lines.clone().into_iter()));
+        let solution = parse()
+            .map_err(|source| Error::Parse { day, source })
+            .and_then(|mut solution| {
+                set_params(day, solution.as_mut(), params)?;
+                Ok(solution)
+            });
+        let solution = match solution {
+            Ok(solution) => solution,
+            Err(error) => {
+                rows.push(vec![day.to_string(), "error".to_string()]);
+                errors.push(error);
+                continue;
+            }
+        };
+        rows.push(bench_row(day, "parse", &measure(settings, parse)));
+
+        for part in parts(part) {
+            let solve = || match part {
+                1 => solution.part_one(),
+                _ => solution.part_two(),
+            };
+            match solve() {
+                Ok(_) => rows.push(bench_row(
+                    day,
+                    &format!("part {part}"),
+                    &measure(settings, solve),
+                )),
+                Err(SolveError::NotImplemented) => rows.push(vec![
+                    day.to_string(),
+                    format!("part {part}"),
+                    "not implemented".to_string(),
+                ]),
+                Err(source) => {
+                    rows.push(vec![
+                        day.to_string(),
+                        format!("part {part}"),
+                        "error".to_string(),
+                    ]);
+                    errors.push(Error::Solve { day, part, source });
+                }
+            }
+        }
+    }
 
-    let (part_one, duration) = time_execution(|| solution.part_one());
-    println!("Part 1: {part_one} (took {duration} seconds)");
+    print_table(&headers, &rows);
+    for error in errors.iter() {
+        println!();
+        println!("error: {error}");
+    }
+
+    match errors.is_empty() {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}
+
+/// Downloads the puzzle input for several days, keeping any already downloaded.
+fn fetch(year: usize, days: &[usize], base_url: &str) -> ExitCode {
+    let mut missing = Vec::new();
+    for &day in days {
+        let path = InputSource::Puzzle.path(year, day);
+        match path.exists() {
+            true => println!("Day {day}: already downloaded to {}", path.display()),
+            false => missing.push((day, path)),
+        }
+    }
+    if missing.is_empty() {
+        return ExitCode::SUCCESS;
+    }
 
-    println!("==================================================");
-    let (part_two, duration) = time_execution(|| solution.part_two());
-    println!("Part 2: {part_two} (took {duration} seconds)");
+    let client = match Client::from_session_file(base_url, client::SESSION_PATH) {
+        Ok(client) => client,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    for (day, path) in missing {
+        match client.cache_input(year, day, &path) {
+            Ok(_) => println!("Day {day}: downloaded to {}", path.display()),
+            Err(error) => {
+                eprintln!("error: {error}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Creates the module and example file for a new day, and adds the example
+/// to the manifest of examples.
+fn new_day(year: usize, day: usize, title: &str) -> Result<(), Error> {
+    let example_dir = InputSource::Example.path(year, day);
+    let example_dir = example_dir.parent().unwrap_or(Path::new("."));
+    for path in scaffold::create(year, day, title, Path::new("src"), example_dir)? {
+        println!("Created {}", path.display());
+    }
+    if scaffold::add_example(Path::new(examples::MANIFEST), year, day)? {
+        println!("Added the example to {}", examples::MANIFEST);
+    }
+    Ok(())
+}
+
+/// Solves a part from the puzzle input and submits the answer, unless it's
+/// already known to be right or wrong. The outcome is recorded in the ledger.
+fn submit(year: usize, day: usize, part: u8, base_url: &str) -> Result<ExitCode, Error> {
+    let dir = ledger::dir(year);
+    let mut ledger = Ledger::load(&dir, day)?;
+    let input = load_input(InputSource::Puzzle.path(year, day))?;
+    let solution = get_solution(year, day, input, &[])?;
+    let answer = solve_part(day, part, solution.as_ref(), None).answer?;
+
+    if let Some(confirmed) = ledger.confirmed(part) {
+        if *confirmed == answer {
+            println!("Day {day} part {part}: {answer} was already accepted");
+            return Ok(ExitCode::SUCCESS);
+        }
+        println!(
+            "Day {day} part {part}: not submitting {answer}, {confirmed} was already accepted"
+        );
+        return Ok(ExitCode::FAILURE);
+    }
+    if let Some(rejected) = ledger.known_wrong(part, &answer) {
+        println!(
+            "Day {day} part {part}: not submitting {answer}, {} was {}",
+            rejected.answer, rejected.hint
+        );
+        return Ok(ExitCode::FAILURE);
+    }
+    if answer.is_multiline() {
+        println!("Day {day} part {part}: the answer needs reading before it can be submitted");
+        println!("{answer}");
+        return Ok(ExitCode::FAILURE);
+    }
+
+    let client = Client::from_session_file(base_url, client::SESSION_PATH)?;
+    println!("Day {day} part {part}: submitting {answer}...");
+    let exit = match client.submit(year, day, part, &answer)? {
+        Outcome::Correct => {
+            println!("That's the right answer!");
+            ledger.record(part, answer);
+            ExitCode::SUCCESS
+        }
+        Outcome::Wrong(hint) => {
+            println!("That's not the right answer, it's {hint}");
+            ledger.reject(part, answer, hint);
+            ExitCode::FAILURE
+        }
+        Outcome::Wait(wait) => {
+            let (minutes, seconds) = (wait.as_secs() / 60, wait.as_secs() % 60);
+            println!("An answer was submitted too recently, wait {minutes}m {seconds}s");
+            return Ok(ExitCode::FAILURE);
+        }
+        Outcome::WrongLevel => {
+            println!("This part can't be submitted, it may have been solved already");
+            return Ok(ExitCode::FAILURE);
+        }
+        Outcome::Unknown(message) => {
+            println!("The response wasn't understood: {message}");
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+
+    ledger.save(&dir, day)?;
+    Ok(exit)
+}
+
+/// Prints the answer to a part and its verdict, starting multi-line answers
+/// on their own line.
+fn print_part(part: u8, run: PartRun) -> Result<(), Error> {
+    if run.is_unimplemented() {
+        println!("Part {part}: not implemented yet");
+        return Ok(());
+    }
+    let answer = run.answer?;
+    let duration = run.seconds;
+    let verdict = run
+        .verdict
+        .as_ref()
+        .map_or(String::new(), |v| format!(" {v}"));
+
+    match answer.is_multiline() {
+        true => println!("Part {part}: (took {duration} seconds){verdict}\n{answer}"),
+        false => println!("Part {part}: {answer} (took {duration} seconds){verdict}"),
+    }
+    if let Some(Verdict::Fail(confirmed)) = run.verdict {
+        match confirmed.is_multiline() {
+            true => println!("The confirmed answer is\n{confirmed}"),
+            false => println!("The confirmed answer is {confirmed}"),
+        }
+    }
+    Ok(())
+}
+
+/// Solves a day with the given parameters, or only the given part of it,
+/// printing each answer.
+fn run(
+    year: usize,
+    day: usize,
+    part: Option<u8>,
+    params: &[(String, String)],
+    source: &InputSource,
+) -> Result<(), Error> {
+    let input = load_input(source.path(year, day))?;
+    let solution = get_solution(year, day, input, params)?;
+    let ledger = load_ledger(year, day, source, params)?;
+
+    println!("Solving {year} day {day}...");
+    for part in parts(part) {
+        let run = solve_part(day, part, solution.as_ref(), ledger.as_ref());
+        println!("==================================================");
+        print_part(part, run)?;
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let command = match Command::parse(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("error: {message}");
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (year, days, part, params, source, format) = match command {
+        Command::Help => {
+            print_help();
+            return ExitCode::SUCCESS;
+        }
+        Command::List => {
+            print_list();
+            return ExitCode::SUCCESS;
+        }
+        Command::Solve {
+            year,
+            days,
+            part,
+            params,
+            input,
+            format,
+        } => (year, days, part, params, input, format),
+        Command::Bench {
+            year,
+            days,
+            part,
+            params,
+            input,
+            settings,
+        } => return run_bench(year, &days, part, &params, &input, &settings),
+        Command::Record { year, days } => return record(year, &days),
+        Command::Verify { year, days } => return verify(year, &days),
+        Command::Fetch {
+            year,
+            days,
+            base_url,
+        } => return fetch(year, &days, &base_url),
+        Command::New { year, day, title } => {
+            return match new_day(year, day, &title) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(error) => {
+                    eprintln!("error: {error}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Submit {
+            year,
+            day,
+            part,
+            base_url,
+        } => {
+            return match submit(year, day, part, &base_url) {
+                Ok(exit) => exit,
+                Err(error) => {
+                    eprintln!("error: {error}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+    };
+
+    let (Format::Text, [day]) = (format, &days[..]) else {
+        return run_all(year, &days, part, &params, &source, format);
+    };
+
+    match run(year, *day, part, &params, &source) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
 }
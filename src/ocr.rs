@@ -0,0 +1,111 @@
+//! Reading the capital letters some puzzles draw as their answer, such as
+//! the screen in Day 10. Letters are 4 pixels wide and 6 tall in a fixed
+//! font. Anything that isn't made up of known letters is left for the caller
+//! to show as `Answer::Pixels`, so an unknown letter is never misread.
+
+const WIDTH: usize = 4;
+const HEIGHT: usize = 6;
+
+/// The letters of the font drawn by puzzles such as Day 10, row by row. Each
+/// letter is 4 pixels wide and 6 tall, with a blank column between letters.
+const FONT: &[(char, [&str; HEIGHT])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Reads the letters drawn in an image, where lit pixels are `true`.
+/// Returns `None` if the image isn't made up entirely of known letters.
+pub fn read(pixels: &[Vec<bool>]) -> Option<String> {
+    if pixels.len() != HEIGHT {
+        return None;
+    }
+    let width = pixels[0].len();
+    if width == 0 || pixels.iter().any(|row| row.len() != width) {
+        return None;
+    }
+
+    // The blank column after the last letter may be missing
+    let count = width.div_ceil(WIDTH + 1);
+    if count * (WIDTH + 1) - width > 1 {
+        return None;
+    }
+
+    (0..count)
+        .map(|i| {
+            let start = i * (WIDTH + 1);
+            let gap_is_blank = pixels
+                .iter()
+                .all(|row| !row.get(start + WIDTH).unwrap_or(&false));
+            match gap_is_blank {
+                true => letter(pixels, start),
+                false => None,
+            }
+        })
+        .collect()
+}
+
+/// Finds the letter drawn in the columns from `start`.
+fn letter(pixels: &[Vec<bool>], start: usize) -> Option<char> {
+    FONT.iter()
+        .find(|(_, glyph)| {
+            glyph.iter().zip(pixels).all(|(glyph_row, row)| {
+                glyph_row
+                    .chars()
+                    .zip(&row[start..start + WIDTH])
+                    .all(|(c, &lit)| (c == '#') == lit)
+            })
+        })
+        .map(|&(letter, _)| letter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Draws an image from rows of `#` and `.`.
+    fn image(rows: &[&str]) -> Vec<Vec<bool>> {
+        rows.iter()
+            .map(|row| row.chars().map(|c| c == '#').collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_read() {
+        let letters = image(&[
+            "###..#....###...##..####.###...##..#....",
+            "#..#.#....#..#.#..#.#....#..#.#..#.#....",
+            "#..#.#....#..#.#..#.###..###..#....#....",
+            "###..#....###..####.#....#..#.#....#....",
+            "#....#....#....#..#.#....#..#.#..#.#....",
+            "#....####.#....#..#.#....###...##..####.",
+        ]);
+        assert_eq!(read(&letters), Some("PLPAFBCL".to_string()));
+
+        // Without the blank column after the last letter
+        let trimmed: Vec<Vec<bool>> = letters.iter().map(|row| row[..39].to_vec()).collect();
+        assert_eq!(read(&trimmed), Some("PLPAFBCL".to_string()));
+    }
+
+    #[test]
+    fn test_read_unknown() {
+        let unknown = image(&["#.#.", ".#.#", "#.#.", ".#.#", "#.#.", ".#.#"]);
+        assert_eq!(read(&unknown), None);
+        assert_eq!(read(&image(&["####"])), None);
+        assert_eq!(read(&[]), None);
+    }
+}
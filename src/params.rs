@@ -0,0 +1,98 @@
+use std::str::FromStr;
+
+use crate::error::ParamError;
+
+/// Declares the tunable constants of a day, such as the row to search in
+/// Day 15, so they can be overridden with `--param key=value`. Each one is a
+/// field of the day's struct, set to the value for the puzzle input when the
+/// input is parsed, and is named by its field. Used inside `impl Solution`:
+///
+/// ```ignore
+/// params!(y_target, limit);
+/// ```
+macro_rules! params {
+    ($($name:ident),+ $(,)?) => {
+        fn set_param(&mut self, key: &str, value: &str) -> Result<(), $crate::error::ParamError> {
+            match key {
+                $(stringify!($name) => self.$name = $crate::params::parse(key, value)?,)+
+                _ => {
+                    return Err($crate::error::ParamError::Unknown {
+                        key: key.to_string(),
+                        known: vec![$(stringify!($name)),+],
+                    })
+                }
+            }
+            Ok(())
+        }
+    };
+}
+
+/// Parses the value of a parameter as the type of its field.
+pub fn parse<T: FromStr>(key: &str, value: &str) -> Result<T, ParamError> {
+    value.trim().parse().map_err(|_| ParamError::Invalid {
+        key: key.to_string(),
+        value: value.to_string(),
+    })
+}
+
+/// Splits an override such as `y_target=10` into its key and value.
+pub fn split(arg: &str) -> Option<(String, String)> {
+    let (key, value) = arg.split_once('=')?;
+    match key.trim() {
+        "" => None,
+        key => Some((key.to_string(), value.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::SolveError;
+    use crate::utils::{Answer, Solution};
+
+    struct Tunable {
+        rounds: usize,
+        row: isize,
+    }
+
+    impl Solution for Tunable {
+        fn part_one(&self) -> Result<Answer, SolveError> {
+            Ok((self.rounds as isize * self.row).into())
+        }
+
+        params!(rounds, row);
+    }
+
+    #[test]
+    fn test_set_param() {
+        let mut day = Tunable { rounds: 20, row: 2 };
+        day.set_param("rounds", "10").unwrap();
+        day.set_param("row", "-3").unwrap();
+        assert_eq!(day.part_one().unwrap(), -30);
+
+        assert_eq!(
+            day.set_param("rounds", "-1"),
+            Err(ParamError::Invalid {
+                key: "rounds".to_string(),
+                value: "-1".to_string(),
+            })
+        );
+        assert_eq!(
+            day.set_param("minutes", "30"),
+            Err(ParamError::Unknown {
+                key: "minutes".to_string(),
+                known: vec!["rounds", "row"],
+            })
+        );
+    }
+
+    #[test]
+    fn test_split() {
+        assert_eq!(
+            split("y_target=10"),
+            Some(("y_target".to_string(), "10".to_string()))
+        );
+        assert_eq!(split("y_target"), None);
+        assert_eq!(split("=10"), None);
+    }
+}
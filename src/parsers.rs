@@ -0,0 +1,166 @@
+//! Parsers for the pieces that come up in many puzzle inputs, built with
+//! nom, along with helpers that parse whole lines and report where a line
+//! stopped making sense.
+
+use std::str::FromStr;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{char, digit1, one_of, space0};
+use nom::combinator::{map_res, opt, recognize};
+use nom::multi::separated_list1;
+use nom::sequence::{pair, preceded, separated_pair, terminated};
+use nom::IResult;
+
+use crate::error::ParseError;
+
+/// An unsigned integer of any type, failing if it doesn't fit.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// An integer of any type with an optional sign, failing if it doesn't fit.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// A list of at least one item separated by commas, which may be followed
+/// by spaces, as in `1,2,3` or `AA, BB`.
+pub fn comma_list<'a, T>(
+    item: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_list1(terminated(char(','), space0), item)
+}
+
+/// A pair of coordinates separated by a comma, such as `498,4`.
+pub fn point<T: FromStr>(input: &str) -> IResult<&str, (T, T)> {
+    separated_pair(signed, char(','), signed)(input)
+}
+
+/// A value given for a key, as in `x=-2` or `rate=13`.
+pub fn key_value<'a, T>(
+    key: &'static str,
+    value: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, T> {
+    preceded(pair(tag(key), char('=')), value)
+}
+
+/// Parses a line with a parser that must use up the whole line. If it
+/// doesn't, the error points at the column where parsing stopped, with
+/// `expected` as the reason.
+pub fn parse_line<'a, T>(
+    line: usize,
+    text: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+    expected: &str,
+) -> Result<T, ParseError> {
+    let rest = match parser(text) {
+        Ok(("", parsed)) => return Ok(parsed),
+        Ok((rest, _)) => rest,
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => error.input,
+        Err(nom::Err::Incomplete(_)) => "",
+    };
+    let column = text[..text.len() - rest.len()].chars().count() + 1;
+    Err(ParseError::new(line, text, expected).at_column(column))
+}
+
+/// Parses every line of the input with [`parse_line`].
+pub fn parse_lines<T>(
+    input: impl Iterator<Item = String>,
+    mut parser: impl FnMut(&str) -> IResult<&str, T>,
+    expected: &str,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .enumerate()
+        .map(|(i, text)| parse_line(i + 1, &text, &mut parser, expected))
+        .collect()
+}
+
+/// Splits the input into blocks of lines separated by blank lines, keeping
+/// the 1-based number of each line for reporting errors.
+pub fn blocks(input: impl Iterator<Item = String>) -> Vec<Vec<(usize, String)>> {
+    let mut blocks = vec![Vec::new()];
+    for (i, text) in input.enumerate() {
+        match text.trim().is_empty() {
+            true => blocks.push(Vec::new()),
+            false => blocks.last_mut().unwrap().push((i + 1, text)),
+        }
+    }
+    blocks.retain(|block| !block.is_empty());
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::character::complete::alpha1;
+
+    use super::*;
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(unsigned::<u8>("42,"), Ok((",", 42)));
+        assert!(unsigned::<u8>("256").is_err());
+        assert!(unsigned::<u8>("-1").is_err());
+        assert_eq!(signed::<i64>("-17 "), Ok((" ", -17)));
+        assert_eq!(signed::<i64>("+3"), Ok(("", 3)));
+        assert_eq!(point::<isize>("498,-4"), Ok(("", (498, -4))));
+    }
+
+    #[test]
+    fn test_combinators() {
+        assert_eq!(
+            comma_list(unsigned::<u32>)("1,2, 3"),
+            Ok(("", vec![1, 2, 3]))
+        );
+        assert_eq!(comma_list(alpha1)("AA, BB;"), Ok((";", vec!["AA", "BB"])));
+        assert_eq!(key_value("x", signed::<i32>)("x=-2, y"), Ok((", y", -2)));
+        assert!(key_value("y", signed::<i32>)("x=-2").is_err());
+    }
+
+    #[test]
+    fn test_parse_line() {
+        fn path(input: &str) -> IResult<&str, Vec<(usize, usize)>> {
+            comma_list(point)(input)
+        }
+
+        assert_eq!(
+            parse_line(1, "1,2,3,4", path, "expected points"),
+            Ok(vec![(1, 2), (3, 4)])
+        );
+
+        let error = parse_line(3, "1,2,x,4", path, "expected points").unwrap_err();
+        assert_eq!((error.line, error.column), (3, Some(4)));
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 4: expected points\n    | 1,2,x,4\n    |    ^"
+        );
+
+        // Anything left over is an error too
+        let error = parse_line(1, "1,2;", path, "expected points").unwrap_err();
+        assert_eq!(error.column, Some(4));
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let lines = ["1,2", "3,4"].map(String::from).into_iter();
+        assert_eq!(
+            parse_lines(lines, point::<i32>, "expected a point"),
+            Ok(vec![(1, 2), (3, 4)])
+        );
+
+        let lines = ["1,2", "3;4"].map(String::from).into_iter();
+        let error = parse_lines(lines, point::<i32>, "expected a point").unwrap_err();
+        assert_eq!((error.line, error.column), (2, Some(2)));
+    }
+
+    #[test]
+    fn test_blocks() {
+        let lines = ["a", "b", "", "", "c", " "].map(String::from).into_iter();
+        assert_eq!(
+            blocks(lines),
+            [
+                vec![(1, "a".to_string()), (2, "b".to_string())],
+                vec![(5, "c".to_string())],
+            ]
+        );
+    }
+}
@@ -0,0 +1,241 @@
+//! Points on a plane and in space, for days that work with coordinates
+//! rather than a [`Grid`](crate::grid::Grid). Points double as the step
+//! between two points, so `a - b` is how to get from `b` to `a`.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+macro_rules! point {
+    ($point:ident, $($axis:ident),+) => {
+        impl $point {
+            pub const ORIGIN: $point = $point { $($axis: 0),+ };
+
+            pub const fn new($($axis: isize),+) -> Self {
+                $point { $($axis),+ }
+            }
+
+            /// The distance to `other` moving along one axis at a time.
+            pub fn manhattan(self, other: Self) -> isize {
+                0 $(+ (self.$axis - other.$axis).abs())+
+            }
+
+            /// The distance to `other` when diagonal moves are allowed too.
+            pub fn chebyshev(self, other: Self) -> isize {
+                0 $(.max((self.$axis - other.$axis).abs()))+
+            }
+
+            /// The step towards the point: -1, 0 or 1 along each axis.
+            pub fn signum(self) -> Self {
+                $point { $($axis: self.$axis.signum()),+ }
+            }
+
+            /// The smallest coordinates of the two points along each axis.
+            pub fn min(self, other: Self) -> Self {
+                $point { $($axis: self.$axis.min(other.$axis)),+ }
+            }
+
+            /// The largest coordinates of the two points along each axis.
+            pub fn max(self, other: Self) -> Self {
+                $point { $($axis: self.$axis.max(other.$axis)),+ }
+            }
+
+            /// The corners of the smallest box holding every point, as its
+            /// smallest and largest coordinates, or `None` without any points.
+            pub fn bounds(points: impl IntoIterator<Item = Self>) -> Option<(Self, Self)> {
+                points.into_iter().fold(None, |bounds, point| match bounds {
+                    Some((low, high)) => Some((point.min(low), point.max(high))),
+                    None => Some((point, point)),
+                })
+            }
+
+            /// Whether the point is in the box with corners `low` and `high`,
+            /// including its edges.
+            pub fn within(self, low: Self, high: Self) -> bool {
+                true $(&& (low.$axis..=high.$axis).contains(&self.$axis))+
+            }
+        }
+
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($axis: self.$axis + other.$axis),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($axis: self.$axis - other.$axis),+ }
+            }
+        }
+
+        impl Mul<isize> for $point {
+            type Output = Self;
+
+            fn mul(self, n: isize) -> Self {
+                $point { $($axis: self.$axis * n),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($axis: -self.$axis),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+point!(Point2, x, y);
+point!(Point3, x, y, z);
+
+/// Directions on a plane have `y` growing upwards, unlike positions in a
+/// `Grid`, which count rows downwards.
+impl Point2 {
+    pub const UP: Point2 = Point2::new(0, 1);
+    pub const DOWN: Point2 = Point2::new(0, -1);
+    pub const LEFT: Point2 = Point2::new(-1, 0);
+    pub const RIGHT: Point2 = Point2::new(1, 0);
+
+    /// The steps to the four neighbours of a point.
+    pub const ORTHOGONAL: [Point2; 4] = [Point2::UP, Point2::RIGHT, Point2::DOWN, Point2::LEFT];
+
+    /// The steps to the four diagonal neighbours of a point.
+    pub const DIAGONAL: [Point2; 4] = [
+        Point2::new(1, 1),
+        Point2::new(1, -1),
+        Point2::new(-1, -1),
+        Point2::new(-1, 1),
+    ];
+
+    /// The four points next to this one, not counting diagonals.
+    pub fn neighbours(self) -> impl Iterator<Item = Point2> {
+        Point2::ORTHOGONAL.into_iter().map(move |step| self + step)
+    }
+
+    /// The eight points around this one, including diagonals.
+    pub fn all_neighbours(self) -> impl Iterator<Item = Point2> {
+        Point2::ORTHOGONAL
+            .into_iter()
+            .chain(Point2::DIAGONAL)
+            .map(move |step| self + step)
+    }
+}
+
+impl Point3 {
+    /// The steps to the six neighbours of a point, which share a face with it.
+    pub const ORTHOGONAL: [Point3; 6] = [
+        Point3::new(1, 0, 0),
+        Point3::new(-1, 0, 0),
+        Point3::new(0, 1, 0),
+        Point3::new(0, -1, 0),
+        Point3::new(0, 0, 1),
+        Point3::new(0, 0, -1),
+    ];
+
+    /// The six points sharing a face with this one.
+    pub fn neighbours(self) -> impl Iterator<Item = Point3> {
+        Point3::ORTHOGONAL.into_iter().map(move |step| self + step)
+    }
+}
+
+impl From<(isize, isize)> for Point2 {
+    fn from((x, y): (isize, isize)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl From<(isize, isize, isize)> for Point3 {
+    fn from((x, y, z): (isize, isize, isize)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut a = Point2::new(3, -2);
+        let b = Point2::new(1, 4);
+        assert_eq!(a + b, Point2::new(4, 2));
+        assert_eq!(a - b, Point2::new(2, -6));
+        assert_eq!(b * 3, Point2::new(3, 12));
+        assert_eq!(-a, Point2::new(-3, 2));
+
+        a += Point2::UP;
+        assert_eq!(a, Point2::new(3, -1));
+        a -= Point2::RIGHT * 3;
+        assert_eq!(a, Point2::new(0, -1));
+        assert_eq!(
+            Point3::new(1, 2, 3) + Point3::from((1, 1, 1)),
+            Point3::new(2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point2::new(8, 7);
+        let b = Point2::new(2, 10);
+        assert_eq!(a.manhattan(b), 9);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!((b - a).signum(), Point2::new(-1, 1));
+        assert_eq!(Point3::new(1, 1, 1).manhattan(Point3::ORIGIN), 3);
+        assert_eq!(Point3::new(2, -5, 1).chebyshev(Point3::ORIGIN), 5);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let point = Point2::new(1, 1);
+        assert_eq!(point.neighbours().count(), 4);
+        assert!(point
+            .all_neighbours()
+            .all(|other| point.chebyshev(other) == 1));
+        assert_eq!(point.all_neighbours().count(), 8);
+        assert!(Point3::ORIGIN
+            .neighbours()
+            .all(|other| other.manhattan(Point3::ORIGIN) == 1));
+    }
+
+    #[test]
+    fn test_bounds() {
+        let points = [
+            Point3::new(2, 2, 2),
+            Point3::new(1, 3, 2),
+            Point3::new(3, 2, 5),
+        ];
+        let (low, high) = Point3::bounds(points).unwrap();
+        assert_eq!((low, high), (Point3::new(1, 2, 2), Point3::new(3, 3, 5)));
+        assert!(points.iter().all(|point| point.within(low, high)));
+        assert!(!Point3::ORIGIN.within(low, high));
+        assert_eq!(Point2::bounds([]), None);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::error::ParseError;
+use crate::utils::Solution;
+
+/// Builds the solution for a day from the lines of its input.
+pub type Constructor =
+    fn(Box<dyn Iterator<Item = String>>) -> Result<Box<dyn Solution>, ParseError>;
+
+/// A day with a solution, declared by the day's module using `register!`.
+pub struct Registration {
+    pub year: usize,
+    pub day: usize,
+    pub title: &'static str,
+    pub build: Constructor,
+}
+
+/// Declares a day's solution so the build script can add it to [`DAYS`].
+/// The year comes from the `yearYYYY` module the day is in.
+///
+/// ```ignore
+/// register!(1, Day1, "Calorie Counting");
+/// ```
+macro_rules! register {
+    ($day:literal, $solution:ty, $title:literal) => {
+        pub const REGISTRATION: $crate::registry::Registration = $crate::registry::Registration {
+            year: super::YEAR,
+            day: $day,
+            title: $title,
+            build: |input| {
+                let solution = <$solution as $crate::utils::FromInput>::from_input(input)?;
+                Ok(Box::new(solution))
+            },
+        };
+    };
+}
+
+/// Every registered day, in order of year and then day.
+pub const DAYS: &[Registration] = include!(concat!(env!("OUT_DIR"), "/registrations.rs"));
+
+/// Finds the registration for a day of a year.
+pub fn find(year: usize, day: usize) -> Option<&'static Registration> {
+    DAYS.iter()
+        .find(|registration| registration.year == year && registration.day == day)
+}
+
+/// The registered days of a year, in order.
+pub fn days(year: usize) -> impl Iterator<Item = &'static Registration> {
+    DAYS.iter()
+        .filter(move |registration| registration.year == year)
+}
+
+/// The most recent year with a registered day.
+pub fn latest_year() -> Option<usize> {
+    DAYS.iter().map(|registration| registration.year).max()
+}
@@ -0,0 +1,119 @@
+use crate::error::Error;
+use crate::input::{load_day_input, InputSource};
+use crate::ledger::{self, Ledger};
+use crate::registry;
+use crate::summary::{DayRun, PartRun};
+use crate::utils::{time_execution, Solution};
+
+/// Gets the solution for a day by parsing its input, then overriding its
+/// parameters with the `(key, value)` pairs given.
+pub fn get_solution(
+    year: usize,
+    day: usize,
+    input: impl Iterator<Item = String> + 'static,
+    params: &[(String, String)],
+) -> Result<Box<dyn Solution>, Error> {
+    let registration = registry::find(year, day).ok_or(Error::UnknownDay { year, day })?;
+    let mut solution =
+        (registration.build)(Box::new(input)).map_err(|source| Error::Parse { day, source })?;
+    set_params(day, solution.as_mut(), params)?;
+    Ok(solution)
+}
+
+/// Overrides the parameters of a day's solution with the `(key, value)` pairs given.
+pub fn set_params(
+    day: usize,
+    solution: &mut dyn Solution,
+    params: &[(String, String)],
+) -> Result<(), Error> {
+    for (key, value) in params {
+        solution
+            .set_param(key, value)
+            .map_err(|source| Error::Param { day, source })?;
+    }
+    Ok(())
+}
+
+/// The parts to solve, which is both unless a single part is given.
+pub fn parts(part: Option<u8>) -> impl Iterator<Item = u8> {
+    [1, 2]
+        .into_iter()
+        .filter(move |&p| part.is_none_or(|part| part == p))
+}
+
+/// Solves one part of a day, checking the answer against the ledger if there is one.
+pub fn solve_part(
+    day: usize,
+    part: u8,
+    solution: &dyn Solution,
+    ledger: Option<&Ledger>,
+) -> PartRun {
+    let (answer, seconds) = time_execution(|| match part {
+        1 => solution.part_one(),
+        _ => solution.part_two(),
+    });
+    let answer = answer.map_err(|source| Error::Solve { day, part, source });
+    let verdict = match (&answer, ledger) {
+        (Ok(answer), Some(ledger)) => Some(ledger.check(part, answer)),
+        _ => None,
+    };
+
+    PartRun {
+        answer,
+        seconds,
+        verdict,
+    }
+}
+
+/// Loads the confirmed answers for a day, which only apply to the puzzle
+/// input when none of the parameters are overridden.
+pub fn load_ledger(
+    year: usize,
+    day: usize,
+    source: &InputSource,
+    params: &[(String, String)],
+) -> Result<Option<Ledger>, Error> {
+    match source {
+        InputSource::Puzzle if params.is_empty() => Ledger::load(&ledger::dir(year), day).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Solves a day with the given parameters, skipping days without an input or
+/// solution. Only the given part is solved if there is one, otherwise both are.
+pub fn run_day(
+    year: usize,
+    day: usize,
+    source: &InputSource,
+    part: Option<u8>,
+    params: &[(String, String)],
+) -> DayRun {
+    let input = match load_day_input(year, day, source) {
+        Ok(Ok(input)) => input,
+        Ok(Err(reason)) => return DayRun::Skipped(reason),
+        Err(error) => return DayRun::Failed { error, part },
+    };
+
+    let (solution, parse_seconds) = time_execution(move || get_solution(year, day, input, params));
+    let solution = match solution {
+        Ok(solution) => solution,
+        Err(Error::UnknownDay { .. }) => return DayRun::Skipped("no solution".to_string()),
+        Err(error) => return DayRun::Failed { error, part },
+    };
+
+    let ledger = match load_ledger(year, day, source, params) {
+        Ok(ledger) => ledger,
+        Err(error) => return DayRun::Failed { error, part },
+    };
+
+    let mut runs = [None, None];
+    for part in parts(part) {
+        runs[part as usize - 1] = Some(solve_part(day, part, solution.as_ref(), ledger.as_ref()));
+    }
+    let [one, two] = runs;
+    DayRun::Solved {
+        parse_seconds,
+        one,
+        two,
+    }
+}
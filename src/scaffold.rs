@@ -0,0 +1,211 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::error::Error;
+use crate::examples::Manifest;
+
+/// The module created for a new day, where `{day}` and `{title}` are filled in.
+/// Both parts report that they aren't implemented until they're written, so
+/// a new day never stops the others from running.
+const TEMPLATE: &str = r#"use crate::error::{ParseError, SolveError};
+use crate::utils::{Answer, FromInput, Solution};
+
+register!({day}, Day{day}, "{title}");
+
+/// Model the problem for Day {day} using this struct
+pub struct Day{day};
+
+impl FromInput for Day{day} {
+    fn from_input(_input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        Ok(Day{day})
+    }
+}
+
+impl Solution for Day{day} {
+    fn part_one(&self) -> Result<Answer, SolveError> {
+        Err(SolveError::NotImplemented)
+    }
+
+    // Part two is reported as not implemented until `part_two` is added here
+}
+"#;
+
+/// The entry added to the manifest of examples for a new day, which checks
+/// nothing until the answers are filled in.
+const EXAMPLE: &str = "
+[[example]]
+year = {year}
+day = {day}
+# part_one =
+# part_two =
+";
+
+/// The module for a new day, which the build script registers automatically.
+pub fn module(day: usize, title: &str) -> String {
+    TEMPLATE
+        .replace("{day}", &day.to_string())
+        .replace("{title}", &title.replace('"', "\\\""))
+}
+
+/// Creates the module for a new day in the `yearYYYY` directory in `src_dir`,
+/// along with an empty file for its example in `example_dir`, returning the
+/// paths created. Nothing is written if either file already exists.
+pub fn create(
+    year: usize,
+    day: usize,
+    title: &str,
+    src_dir: &Path,
+    example_dir: &Path,
+) -> Result<Vec<PathBuf>, Error> {
+    let module_path = src_dir
+        .join(format!("year{year}"))
+        .join(format!("day{day}.rs"));
+    let files = [
+        (module_path, module(day, title)),
+        (example_dir.join(format!("{day}.txt")), String::new()),
+    ];
+    if let Some((path, _)) = files.iter().find(|(path, _)| path.exists()) {
+        return Err(Error::AlreadyExists(path.clone()));
+    }
+
+    for (path, contents) in files.iter() {
+        let io_error = |source| Error::Io {
+            path: path.clone(),
+            source,
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+
+        // Never replace a file, even one created since checking
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .map_err(|source| match source.kind() {
+                io::ErrorKind::AlreadyExists => Error::AlreadyExists(path.clone()),
+                _ => io_error(source),
+            })?;
+        file.write_all(contents.as_bytes()).map_err(io_error)?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Adds an entry for the example of a new day to the manifest of examples,
+/// unless the day already has one. Returns whether the entry was added.
+pub fn add_example(manifest: &Path, year: usize, day: usize) -> Result<bool, Error> {
+    let io_error = |source| Error::Io {
+        path: manifest.to_path_buf(),
+        source,
+    };
+    let examples = match manifest.exists() {
+        true => Manifest::load(manifest)?.examples,
+        false => Vec::new(),
+    };
+    if examples
+        .iter()
+        .any(|example| example.year == year && example.day == day)
+    {
+        return Ok(false);
+    }
+
+    let entry = EXAMPLE
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string());
+    fs::OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(manifest)
+        .and_then(|mut file| file.write_all(entry.as_bytes()))
+        .map_err(io_error)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::SolveError;
+    use crate::runner::solve_part;
+
+    /// The year `register!` gives the scaffolded day below.
+    const YEAR: usize = 2022;
+
+    /// What `new 2022 19 --title "Not Enough Minerals"` writes, compiled so
+    /// it can be run like any other day.
+    mod day19 {
+        include!("scaffold/day19.rs");
+    }
+
+    #[test]
+    fn test_module() {
+        let module = module(19, "Not Enough Minerals");
+        assert!(module.contains("register!(19, Day19, \"Not Enough Minerals\");"));
+        assert!(module.contains("pub struct Day19;"));
+        assert!(!module.contains("fn part_two"));
+        assert!(!module.contains("mod tests"));
+        assert!(!module.contains("todo!"));
+    }
+
+    #[test]
+    fn test_scaffolded_day_runs() {
+        assert_eq!(
+            module(19, "Not Enough Minerals"),
+            include_str!("scaffold/day19.rs")
+        );
+
+        // Both parts report that they aren't implemented rather than panicking
+        let solution = (day19::REGISTRATION.build)(Box::new(std::iter::empty())).unwrap();
+        for part in [1, 2] {
+            let run = solve_part(19, part, solution.as_ref(), None);
+            assert!(matches!(
+                run.answer,
+                Err(Error::Solve {
+                    source: SolveError::NotImplemented,
+                    ..
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_create() {
+        let dir = std::env::temp_dir().join(format!("scaffold-{}", std::process::id()));
+        let (src, examples) = (dir.join("src"), dir.join(".test_input"));
+
+        let created = create(2022, 19, "Day 19", &src, &examples).unwrap();
+        assert_eq!(
+            created,
+            vec![src.join("year2022/day19.rs"), examples.join("19.txt")]
+        );
+        assert_eq!(fs::read_to_string(&created[1]).unwrap(), "");
+
+        // Existing files are left alone
+        fs::write(&created[0], "// solved").unwrap();
+        fs::remove_file(&created[1]).unwrap();
+        assert!(matches!(
+            create(2022, 19, "Day 19", &src, &examples),
+            Err(Error::AlreadyExists(path)) if path == created[0]
+        ));
+        assert_eq!(fs::read_to_string(&created[0]).unwrap(), "// solved");
+        assert!(!created[1].exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_add_example() {
+        let dir = std::env::temp_dir().join(format!("manifest-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let manifest = dir.join("examples.toml");
+        fs::write(&manifest, "[[example]]\nyear = 2022\nday = 18\n").unwrap();
+
+        assert!(add_example(&manifest, 2022, 19).unwrap());
+        assert!(!add_example(&manifest, 2022, 19).unwrap());
+        let examples = Manifest::load(&manifest).unwrap().examples;
+        assert_eq!(examples.len(), 2);
+        assert_eq!((examples[1].year, examples[1].day), (2022, 19));
+        assert_eq!(examples[1].part_one, None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
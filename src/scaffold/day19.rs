@@ -0,0 +1,21 @@
+use crate::error::{ParseError, SolveError};
+use crate::utils::{Answer, FromInput, Solution};
+
+register!(19, Day19, "Not Enough Minerals");
+
+/// Model the problem for Day 19 using this struct
+pub struct Day19;
+
+impl FromInput for Day19 {
+    fn from_input(_input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        Ok(Day19)
+    }
+}
+
+impl Solution for Day19 {
+    fn part_one(&self) -> Result<Answer, SolveError> {
+        Err(SolveError::NotImplemented)
+    }
+
+    // Part two is reported as not implemented until `part_two` is added here
+}
@@ -0,0 +1,272 @@
+//! Searches over graphs given as a function from each state to its
+//! neighbours, such as the positions a path can step to next. States can be
+//! anything hashable, so a state can carry more than a position.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// What a search found: the cost of the cheapest path to every state it
+/// reached, and the state before each one on that path.
+#[derive(Debug, Clone)]
+pub struct Search<S, C> {
+    pub distances: HashMap<S, C>,
+    pub predecessors: HashMap<S, S>,
+    /// The goal the search stopped at, if it found one
+    pub goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    fn new() -> Self {
+        Search {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// The cost of the cheapest path to `state`, if it was reached.
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    /// The cost of the cheapest path to the goal, if one was found.
+    pub fn cost(&self) -> Option<C> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    /// The states on the cheapest path to `state`, from the start it was
+    /// reached from up to `state` itself.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors.get(path.last()?) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Finds the fewest steps from any of `starts` to every state it can reach,
+/// stopping early at the first state where `is_goal` is true.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.distances.contains_key(&start) {
+            search.distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        let steps = search.distances[&state] + 1;
+        for next in neighbours(&state) {
+            if !search.distances.contains_key(&next) {
+                search.distances.insert(next.clone(), steps);
+                search.predecessors.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Finds every state reachable from any of `starts`.
+pub fn flood_fill<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+) -> HashSet<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    bfs(starts, neighbours, |_| false)
+        .distances
+        .into_keys()
+        .collect()
+}
+
+/// Finds the cheapest paths from any of `starts`, where `neighbours` gives
+/// each state that can be moved to along with the cost of moving there,
+/// stopping early at the first state where `is_goal` is true.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but looks at the states `heuristic` says are closest to
+/// a goal first. The heuristic must never overestimate the cost to the
+/// nearest goal, or the path found may not be the cheapest.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if !search.distances.contains_key(&start) {
+            search.distances.insert(start.clone(), C::default());
+            heap.push(Entry {
+                estimate: heuristic(&start),
+                cost: C::default(),
+                state: start,
+            });
+        }
+    }
+
+    while let Some(Entry { cost, state, .. }) = heap.pop() {
+        // A cheaper path to the state was found after this one was queued
+        if search.distances[&state] < cost {
+            continue;
+        }
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            if search
+                .distances
+                .get(&next)
+                .is_none_or(|&best| next_cost < best)
+            {
+                search.distances.insert(next.clone(), next_cost);
+                search.predecessors.insert(next.clone(), state.clone());
+                heap.push(Entry {
+                    estimate: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+    search
+}
+
+/// A state waiting to be looked at, ordered so the heap gives the one with
+/// the lowest estimated total cost first.
+struct Entry<S, C> {
+    estimate: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    /// A maze where `#` is a wall and digits cost that much to step onto.
+    fn maze(rows: &[&str]) -> Grid<char> {
+        Grid::parse(rows.iter().map(|row| row.to_string()), Some, "").unwrap()
+    }
+
+    fn open(grid: &Grid<char>, position: (usize, usize)) -> Vec<(usize, usize)> {
+        grid.neighbours(position)
+            .filter(|&next| grid[next] != '#')
+            .collect()
+    }
+
+    fn weighted(grid: &Grid<char>, position: (usize, usize)) -> Vec<((usize, usize), u32)> {
+        grid.neighbours(position)
+            .filter_map(|next| Some((next, grid[next].to_digit(10)?)))
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze(&["...#", ".#..", "...#"]);
+        let search = bfs([(0, 0)], |&p| open(&grid, p), |&p| p == (3, 1));
+        assert_eq!(search.cost(), Some(4));
+        assert_eq!(search.goal, Some((3, 1)));
+        assert_eq!(
+            search.path(&(3, 1)),
+            Some(vec![(0, 0), (1, 0), (2, 0), (2, 1), (3, 1)])
+        );
+
+        // The nearest of several starts is used, and a missing goal isn't found
+        let search = bfs([(0, 0), (0, 2)], |&p| open(&grid, p), |&p| p == (2, 2));
+        assert_eq!(search.path(&(2, 2)).unwrap().first(), Some(&(0, 2)));
+        let search = bfs([(0, 0)], |&p| open(&grid, p), |&p| p == (3, 0));
+        assert_eq!((search.goal, search.cost()), (None, None));
+        assert_eq!(search.path(&(3, 0)), None);
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = maze(&["..#.", "..#.", "###."]);
+        assert_eq!(flood_fill([(0, 0)], |&p| open(&grid, p)).len(), 4);
+        assert_eq!(flood_fill([(3, 0)], |&p| open(&grid, p)).len(), 3);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let grid = maze(&["1163", "1381", "2136"]);
+        let goal = (3, 2);
+        let search = dijkstra([(0, 0)], |&p| weighted(&grid, p), |&p| p == goal);
+        assert_eq!(search.cost(), Some(13));
+        let path = search.path(&goal).unwrap();
+        let cost: u32 = path[1..]
+            .iter()
+            .map(|&p| grid[p].to_digit(10).unwrap())
+            .sum();
+        assert_eq!(cost, 13);
+
+        // The distance to a manhattan heuristic never overestimates here
+        let manhattan = |&(x, y): &(usize, usize)| (goal.0.abs_diff(x) + goal.1.abs_diff(y)) as u32;
+        let search = astar([(0, 0)], |&p| weighted(&grid, p), manhattan, |&p| p == goal);
+        assert_eq!(search.cost(), Some(13));
+    }
+}
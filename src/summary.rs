@@ -0,0 +1,361 @@
+use serde::Serialize;
+
+use crate::error::{Error, SolveError};
+use crate::ledger::Verdict;
+use crate::runner::parts;
+use crate::utils::Answer;
+
+/// The answer and duration in seconds for one part of a day.
+pub struct PartRun {
+    pub answer: Result<Answer, Error>,
+    pub seconds: f32,
+    /// How the answer compares to the confirmed answer, when checked
+    pub verdict: Option<Verdict>,
+}
+
+impl PartRun {
+    /// Whether the day doesn't have a solution for the part yet.
+    pub fn is_unimplemented(&self) -> bool {
+        matches!(
+            self.answer,
+            Err(Error::Solve {
+                source: SolveError::NotImplemented,
+                ..
+            })
+        )
+    }
+
+    /// Whether the part couldn't be solved. Parts that aren't implemented
+    /// yet don't count as failures.
+    pub fn is_failure(&self) -> bool {
+        self.answer.is_err() && !self.is_unimplemented()
+    }
+}
+
+/// What happened when solving one day.
+pub enum DayRun {
+    /// The day wasn't attempted, with the reason why
+    Skipped(String),
+    /// The input for the day couldn't be loaded or parsed. `part` is the part
+    /// that was selected, or `None` if both were
+    Failed { error: Error, part: Option<u8> },
+    /// The selected parts ran after parsing the input, and the others are `None`
+    Solved {
+        parse_seconds: f32,
+        one: Option<PartRun>,
+        two: Option<PartRun>,
+    },
+}
+
+impl DayRun {
+    pub fn is_failure(&self) -> bool {
+        match self {
+            DayRun::Skipped(_) => false,
+            DayRun::Failed { .. } => true,
+            DayRun::Solved { .. } => self.parts().any(|(_, run)| run.is_failure()),
+        }
+    }
+
+    /// Whether either part differs from its confirmed answer.
+    pub fn is_regression(&self) -> bool {
+        self.parts()
+            .any(|(_, run)| matches!(run.verdict, Some(Verdict::Fail(_))))
+    }
+
+    /// The parts that ran, along with their numbers.
+    pub fn parts(&self) -> impl Iterator<Item = (u8, &PartRun)> {
+        let (one, two) = match self {
+            DayRun::Solved { one, two, .. } => (one.as_ref(), two.as_ref()),
+            _ => (None, None),
+        };
+        [(1, one), (2, two)]
+            .into_iter()
+            .filter_map(|(part, run)| Some((part, run?)))
+    }
+}
+
+/// A single line answer for the table, with longer answers shown below it.
+/// Parts that didn't run are shown as `-`.
+fn cell(run: Option<&PartRun>) -> String {
+    let Some(run) = run else {
+        return "-".to_string();
+    };
+    match &run.answer {
+        _ if run.is_unimplemented() => "not implemented".to_string(),
+        Ok(answer) if answer.is_multiline() => "(see below)".to_string(),
+        Ok(answer) => answer.to_string(),
+        Err(_) => "error".to_string(),
+    }
+}
+
+fn verdict(verdict: Option<&Verdict>) -> String {
+    verdict.map_or(String::new(), Verdict::to_string)
+}
+
+fn seconds(seconds: f32) -> String {
+    format!("{seconds:.6}s")
+}
+
+/// Prints a table of the answers and timings for each day, followed by
+/// any multi-line answers, wrong answers and errors. Answers that were
+/// checked against the confirmed answers get a column with the verdict.
+pub fn print_summary(runs: &[(usize, DayRun)]) {
+    let checked = runs
+        .iter()
+        .any(|(_, run)| run.parts().any(|(_, run)| run.verdict.is_some()));
+    let headers: &[&str] = match checked {
+        true => &["Day", "Part 1", "Time", "Check", "Part 2", "Time", "Check"],
+        false => &["Day", "Part 1", "Time", "Part 2", "Time"],
+    };
+    let mut rows = Vec::new();
+    let mut notes = Vec::new();
+    let mut total = 0.0;
+
+    for (day, run) in runs {
+        match run {
+            DayRun::Skipped(reason) => {
+                rows.push(vec![day.to_string(), format!("skipped: {reason}")]);
+            }
+            DayRun::Failed { error, .. } => {
+                rows.push(vec![day.to_string(), "error".to_string()]);
+                notes.push(format!("error: {error}"));
+            }
+            DayRun::Solved { one, two, .. } => {
+                let mut row = vec![day.to_string()];
+                for run in [one.as_ref(), two.as_ref()] {
+                    let time = run.map_or(String::new(), |run| seconds(run.seconds));
+                    row.extend([cell(run), time]);
+                    if checked {
+                        row.push(verdict(run.and_then(|run| run.verdict.as_ref())));
+                    }
+                }
+                rows.push(row);
+
+                for (part, run) in run.parts() {
+                    total += run.seconds;
+                    if let Some(Verdict::Fail(confirmed)) = &run.verdict {
+                        let separator = if confirmed.is_multiline() { '\n' } else { ' ' };
+                        notes.push(format!(
+                            "Day {day} part {part}: FAIL, the confirmed answer is{separator}{confirmed}"
+                        ));
+                    }
+                    match &run.answer {
+                        Ok(answer) if answer.is_multiline() => {
+                            notes.push(format!("Day {day} part {part}:\n{answer}"));
+                        }
+                        Err(error) if run.is_failure() => notes.push(format!("error: {error}")),
+                        _ => (),
+                    }
+                }
+            }
+        }
+    }
+
+    print_table(headers, &rows);
+    println!(" Total: {}", seconds(total));
+
+    for note in notes {
+        println!();
+        println!("{note}");
+    }
+}
+
+/// Prints rows of cells in aligned columns. Rows with fewer cells than
+/// the headers let their last cell span the rest of the table.
+pub fn print_table(headers: &[&str], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in rows.iter().filter(|row| row.len() == headers.len()) {
+        for (width, value) in widths.iter_mut().zip(row) {
+            *width = (*width).max(value.chars().count());
+        }
+    }
+
+    let line = |row: &[String]| {
+        let last = row.len() - 1;
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (value, &width))| match i == last {
+                true => value.clone(),
+                false => format!("{value:<width$}"),
+            })
+            .collect();
+        println!(" {}", cells.join(" | "));
+    };
+
+    let rule: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+    line(&headers.iter().map(|h| h.to_string()).collect::<Vec<_>>());
+    println!("-{}-", rule.join("-+-"));
+    for row in rows.iter() {
+        line(row);
+    }
+    println!("-{}-", rule.join("-+-"));
+}
+
+/// The result of one part of a day, as printed in machine-readable formats.
+/// Times are in seconds, and are missing when the stage didn't run.
+#[derive(Debug, Serialize)]
+pub struct PartResult {
+    pub year: usize,
+    pub day: usize,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub parse_seconds: Option<f32>,
+    pub solve_seconds: Option<f32>,
+    pub error: Option<String>,
+}
+
+/// Flattens the runs for the days of a year into a result for each part.
+/// Skipped days are left out.
+pub fn part_results(year: usize, runs: &[(usize, DayRun)]) -> Vec<PartResult> {
+    let mut results = Vec::new();
+    for (day, run) in runs {
+        match run {
+            DayRun::Skipped(_) => (),
+            DayRun::Failed { error, part } => results.extend(parts(*part).map(|part| PartResult {
+                year,
+                day: *day,
+                part,
+                answer: None,
+                parse_seconds: None,
+                solve_seconds: None,
+                error: Some(error.to_string()),
+            })),
+            DayRun::Solved { parse_seconds, .. } => {
+                results.extend(run.parts().map(|(part, run)| PartResult {
+                    year,
+                    day: *day,
+                    part,
+                    answer: run.answer.as_ref().ok().cloned(),
+                    parse_seconds: Some(*parse_seconds),
+                    solve_seconds: Some(run.seconds),
+                    error: run.answer.as_ref().err().map(Error::to_string),
+                }))
+            }
+        }
+    }
+    results
+}
+
+/// Prints the result of each part as a JSON array.
+pub fn print_json(year: usize, runs: &[(usize, DayRun)]) {
+    let json = serde_json::to_string_pretty(&part_results(year, runs))
+        .expect("results should always serialize");
+    println!("{json}");
+}
+
+/// Prints the result of each part as CSV, quoting fields where needed.
+pub fn print_csv(year: usize, runs: &[(usize, DayRun)]) {
+    println!("year,day,part,answer,parse_seconds,solve_seconds,error");
+    for result in part_results(year, runs) {
+        let optional = |value: Option<String>| value.as_deref().map_or(String::new(), csv_field);
+        println!(
+            "{},{},{},{},{},{},{}",
+            result.year,
+            result.day,
+            result.part,
+            optional(result.answer.map(|answer| answer.to_string())),
+            optional(result.parse_seconds.map(|s| s.to_string())),
+            optional(result.solve_seconds.map(|s| s.to_string())),
+            optional(result.error),
+        );
+    }
+}
+
+/// Quotes a CSV field if it contains a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    match value.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::SolveError;
+
+    #[test]
+    fn test_part_results() {
+        let runs = vec![
+            (1, DayRun::Skipped("no input".to_string())),
+            (
+                10,
+                DayRun::Solved {
+                    parse_seconds: 0.5,
+                    one: Some(PartRun {
+                        answer: Ok(Answer::Int(13140)),
+                        seconds: 0.25,
+                        verdict: None,
+                    }),
+                    two: Some(PartRun {
+                        answer: Err(Error::Solve {
+                            day: 10,
+                            part: 2,
+                            source: SolveError::NoAnswer("blank screen".to_string()),
+                        }),
+                        seconds: 0.125,
+                        verdict: None,
+                    }),
+                },
+            ),
+        ];
+
+        let json = serde_json::to_string(&part_results(2022, &runs)).unwrap();
+        assert_eq!(
+            json,
+            "[{\"year\":2022,\"day\":10,\"part\":1,\"answer\":13140,\"parse_seconds\":0.5,\
+             \"solve_seconds\":0.25,\"error\":null},\
+             {\"year\":2022,\"day\":10,\"part\":2,\"answer\":null,\"parse_seconds\":0.5,\
+             \"solve_seconds\":0.125,\"error\":\"day 10 part 2: no answer found: blank screen\"}]"
+        );
+    }
+
+    #[test]
+    fn test_selected_parts() {
+        let run = DayRun::Solved {
+            parse_seconds: 0.5,
+            one: None,
+            two: Some(PartRun {
+                answer: Err(Error::Solve {
+                    day: 19,
+                    part: 2,
+                    source: SolveError::NotImplemented,
+                }),
+                seconds: 0.0,
+                verdict: None,
+            }),
+        };
+        assert!(!run.is_failure());
+        assert_eq!(run.parts().map(|(part, _)| part).collect::<Vec<_>>(), [2]);
+
+        let results = part_results(2022, &[(19, run)]);
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].error.as_deref(),
+            Some("day 19 part 2: not implemented yet")
+        );
+    }
+
+    #[test]
+    fn test_failed_parts() {
+        let failed = |part| DayRun::Failed {
+            error: Error::UnknownDay {
+                year: 2022,
+                day: 30,
+            },
+            part,
+        };
+        let results = part_results(2022, &[(30, failed(Some(2)))]);
+        assert_eq!(results.iter().map(|r| r.part).collect::<Vec<_>>(), [2]);
+        assert_eq!(part_results(2022, &[(30, failed(None))]).len(), 2);
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("13140"), "13140");
+        assert_eq!(csv_field("a, \"b\""), "\"a, \"\"b\"\"\"");
+        assert_eq!(csv_field("#.\n.#"), "\"#.\n.#\"");
+    }
+}
@@ -1,19 +1,35 @@
 use std::time::Instant;
 
+pub use crate::answer::Answer;
+use crate::error::{ParamError, ParseError, SolveError};
+
 /// Reads the lines from the input file into a relevant
 /// model of the data for the day's solution.
-pub trait FromInput {
-    fn from_input(lines: impl Iterator<Item = String>) -> Self;
+pub trait FromInput: Sized {
+    fn from_input(lines: impl Iterator<Item = String>) -> Result<Self, ParseError>;
 }
 
-/// Solutions for a day of Advent of Code.
+/// Solutions for a day of Advent of Code. Part two only unlocks once part
+/// one is solved, so a day can leave it out until then.
 pub trait Solution {
-    fn part_one(&self) -> String;
-    fn part_two(&self) -> String;
+    fn part_one(&self) -> Result<Answer, SolveError>;
+
+    fn part_two(&self) -> Result<Answer, SolveError> {
+        Err(SolveError::NotImplemented)
+    }
+
+    /// Overrides one of the day's tunable constants, which are declared
+    /// with `params!`. Days without any reject every key.
+    fn set_param(&mut self, key: &str, _value: &str) -> Result<(), ParamError> {
+        Err(ParamError::Unknown {
+            key: key.to_string(),
+            known: Vec::new(),
+        })
+    }
 }
 
 /// Times the execution of a function.
-pub fn time_execution(func: impl Fn() -> String) -> (String, f32) {
+pub fn time_execution<T>(func: impl FnOnce() -> T) -> (T, f32) {
     let timer = Instant::now();
     let result = func();
     let duration = timer.elapsed();
@@ -0,0 +1,65 @@
+use crate::error::{ParseError, SolveError};
+use crate::utils::{Answer, FromInput, Solution};
+
+register!(1, Day1, "Calorie Counting");
+
+/// Model the problem for Day 1 using this struct
+pub struct Day1 {
+    pub elves: Vec<Vec<u32>>,
+}
+
+impl FromInput for Day1 {
+    fn from_input(input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        let mut elves = vec![Vec::new()];
+
+        for (i, line) in input.enumerate() {
+            if line.trim().is_empty() {
+                elves.push(Vec::new());
+                continue;
+            }
+
+            let calories = elves.last_mut().expect("At least one vector");
+            match line.trim().parse() {
+                Ok(number) => calories.push(number),
+                Err(_) => return Err(ParseError::new(i + 1, line, "expected a calorie count")),
+            }
+        }
+
+        Ok(Day1 { elves })
+    }
+}
+
+impl Solution for Day1 {
+    fn part_one(&self) -> Result<Answer, SolveError> {
+        let max: u32 = self
+            .elves
+            .iter()
+            .map(|calories| calories.iter().sum())
+            .max()
+            .ok_or_else(|| SolveError::NoAnswer("no elves in the input".to_string()))?;
+
+        Ok(max.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolveError> {
+        let mut sums: Vec<u32> = self.elves.iter().map(|cals| cals.iter().sum()).collect();
+
+        sums.sort();
+        let result: u32 = sums.iter().rev().take(3).sum();
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_input() {
+        let test_input = ["1000", "2000", "", "lots"].map(String::from).into_iter();
+        let error = Day1::from_input(test_input).err().unwrap();
+
+        assert_eq!(error.line, 4);
+        assert_eq!(error.text, "lots");
+    }
+}
@@ -0,0 +1,123 @@
+use crate::error::{ParseError, SolveError};
+use crate::grid::Grid;
+use crate::ocr;
+use crate::parsers::{parse_line, signed};
+use crate::utils::{Answer, FromInput, Solution};
+use nom::{
+    branch::alt, bytes::complete::tag, combinator::{map, value}, sequence::preceded, IResult,
+};
+
+register!(10, Day10, "Cathode-Ray Tube");
+
+/// Each instruction is either a `noop` or the value to `addx`.
+pub struct Day10(pub Vec<Option<isize>>);
+
+impl FromInput for Day10 {
+    fn from_input(input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        let instructions = input
+            .enumerate()
+            .map(|(i, line)| {
+                parse_line(i + 1, line.trim(), parse_instruction, "expected `noop` or `addx <value>`")
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Day10(instructions))
+    }
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, Option<isize>> {
+    alt((
+        value(None, tag("noop")),
+        map(preceded(tag("addx "), signed), Some),
+    ))(input)
+}
+
+impl Solution for Day10 {
+    fn part_one(&self) -> Result<Answer, SolveError> {
+
+        fn inc(c: &mut isize, answer: &mut isize, x: isize) {
+            *c += 1; 
+            if [20, 60, 100, 140, 180, 220].contains(c) {
+                *answer += *c * x;
+            }
+        }
+
+        let mut answer = 0;
+        let mut x = 1;
+        let mut c = 1;
+
+        for instruction in self.0.iter() {
+            let Some(value) = instruction else {
+                inc(&mut c, &mut answer, x);
+                continue;
+            };
+            inc(&mut c, &mut answer, x);
+            x += value;
+            inc(&mut c, &mut answer, x)
+        }
+        Ok(answer.into())
+
+    }
+
+    fn part_two(&self) -> Result<Answer, SolveError> {
+        /// Draws the pixel for cycle `c`, counting from 0, so the first cycle
+        /// draws the top left pixel. `x` is the sprite's position during the
+        /// cycle, which an `addx` only moves once both its cycles are drawn.
+        fn draw(c: &mut usize, x: isize, grid: &mut Grid<bool>) {
+            let row = *c / grid.width();
+            let col = *c % grid.width();
+            let pos = x - col as isize;
+
+            if let Some(pixel) = grid.get_mut((col, row)).filter(|_| pos.abs() <= 1) {
+                *pixel = true;
+            }
+            *c += 1;
+        }
+
+        let mut x = 1;
+        let mut c = 0;
+        let mut grid = Grid::new(40, 6, false);
+
+        for instruction in self.0.iter() {
+            draw(&mut c, x, &mut grid);
+            if let Some(value) = instruction {
+                draw(&mut c, x, &mut grid);
+                x += value;
+            }
+        }
+
+        // The screen shows letters, but fall back to the image if any aren't known
+        let pixels = grid.to_rows();
+        match ocr::read(&pixels) {
+            Some(letters) => Ok(Answer::Text(letters)),
+            None => Ok(Answer::Pixels(pixels)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen(program: &[&str]) -> String {
+        let day = Day10::from_input(program.iter().map(|line| line.to_string())).unwrap();
+        day.part_two().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_first_cycle_and_addx_timing() {
+        // The first cycle draws the first pixel of each row, and the sprite
+        // starts over pixels 0 to 2, so every row starts with three lit pixels
+        let noops = vec!["noop"; 240];
+        let row = format!("###{}", ".".repeat(37));
+        assert_eq!(screen(&noops), vec![row; 6].join("\n"));
+
+        // Both cycles of `addx 5` are drawn with the sprite at 1, lighting
+        // pixels 0 and 1, and it's over pixels 5 to 7 from the third cycle on
+        let mut program = vec!["addx 5"];
+        program.extend(vec!["noop"; 238]);
+        let first = format!("##...###{}", ".".repeat(32));
+        let rest = format!(".....###{}", ".".repeat(32));
+        let expected = [first, rest.clone(), rest.clone(), rest.clone(), rest.clone(), rest];
+        assert_eq!(screen(&program), expected.join("\n"));
+    }
+}
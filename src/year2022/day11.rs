@@ -0,0 +1,507 @@
+use crate::cycle::{extrapolate_with_cycle, CycleError};
+use crate::error::{ParseError, SolveError};
+use crate::parsers::{blocks, comma_list, parse_line, unsigned};
+use crate::utils::{Answer, FromInput, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, one_of, space0},
+    combinator::{map, value},
+    multi::fold_many0,
+    sequence::{delimited, pair, preceded, terminated},
+    IResult,
+};
+use num_bigint::BigUint;
+use std::hash::Hash;
+
+register!(11, Day11, "Monkey in the Middle");
+
+#[derive(Debug, Clone)]
+pub struct Day11 {
+    pub items: Vec<Vec<usize>>,
+    pub ops: Vec<Expr>,
+    pub test: Vec<usize>,
+    pub _true: Vec<usize>,
+    pub _false: Vec<usize>,
+    pub count: Vec<usize>,
+    /// The rounds played in each part
+    pub rounds_one: usize,
+    pub rounds_two: usize,
+    /// Whether to keep worry levels exactly rather than modulo the divisors,
+    /// to check the answers for a few rounds against
+    pub exact: bool,
+}
+
+/// Parses line `i` of a monkey's notes, which starts with `label` and is
+/// followed by a value like the one in `example`.
+fn note<'a, T>(
+    notes: &'a [(usize, String)],
+    i: usize,
+    label: &'static str,
+    value: impl FnMut(&'a str) -> IResult<&'a str, T>,
+    example: &str,
+) -> Result<T, ParseError> {
+    let expected = format!("expected a line like `{label}{example}`");
+    let Some((line, text)) = notes.get(i) else {
+        return Err(ParseError::new(notes[0].0 + i, "", expected));
+    };
+    parse_line(*line, text.trim_end(), preceded(pair(space0, tag(label)), value), &expected)
+}
+
+/// An arithmetic operator in a monkey's operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+/// The right hand side of a monkey's operation, such as `old * (old + 3)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Old,
+    Number(usize),
+    Binary(Op, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Works out the new worry level from the `old` one.
+    fn eval<D: Domain>(&self, domain: &D, old: &D::Value) -> Result<D::Value, SolveError> {
+        match self {
+            Expr::Old => Ok(old.clone()),
+            Expr::Number(n) => Ok(domain.number(*n)),
+            Expr::Binary(op, a, b) => domain.apply(*op, &a.eval(domain, old)?, &b.eval(domain, old)?),
+        }
+    }
+}
+
+/// Reads the operators between `operand`s from left to right, so `a - b - c`
+/// is `(a - b) - c`.
+fn parse_chain<'a>(
+    input: &'a str,
+    operators: &'static str,
+    operand: fn(&str) -> IResult<&str, Expr>,
+) -> IResult<&'a str, Expr> {
+    let (input, first) = operand(input)?;
+    fold_many0(
+        pair(delimited(space0, one_of(operators), space0), operand),
+        move || first.clone(),
+        |a, (op, b)| {
+            let op = match op {
+                '+' => Op::Add,
+                '-' => Op::Sub,
+                '*' => Op::Mul,
+                _ => Op::Div,
+            };
+            Expr::Binary(op, Box::new(a), Box::new(b))
+        },
+    )(input)
+}
+
+fn parse_expr(input: &str) -> IResult<&str, Expr> {
+    parse_chain(input, "+-", parse_term)
+}
+
+fn parse_term(input: &str) -> IResult<&str, Expr> {
+    parse_chain(input, "*/", parse_factor)
+}
+
+fn parse_factor(input: &str) -> IResult<&str, Expr> {
+    alt((
+        value(Expr::Old, tag("old")),
+        map(unsigned, Expr::Number),
+        delimited(pair(char('('), space0), parse_expr, pair(space0, char(')'))),
+    ))(input)
+}
+
+/// The kind of numbers worry levels are kept as.
+trait Domain {
+    type Value: Clone + Eq + Hash;
+
+    fn number(&self, n: usize) -> Self::Value;
+
+    fn apply(&self, op: Op, a: &Self::Value, b: &Self::Value) -> Result<Self::Value, SolveError>;
+
+    fn is_multiple_of(&self, value: &Self::Value, divisor: usize) -> bool;
+}
+
+fn negative() -> SolveError {
+    SolveError::InvalidInput("an operation makes a worry level negative".to_string())
+}
+
+fn division_by_zero() -> SolveError {
+    SolveError::InvalidInput("an operation divides by zero".to_string())
+}
+
+fn overflow() -> SolveError {
+    SolveError::InvalidInput(format!(
+        "a worry level goes over {}, try `--param exact=true`",
+        usize::MAX
+    ))
+}
+
+/// Worry levels as they are.
+struct Plain;
+
+impl Domain for Plain {
+    type Value = usize;
+
+    fn number(&self, n: usize) -> usize {
+        n
+    }
+
+    fn apply(&self, op: Op, a: &usize, b: &usize) -> Result<usize, SolveError> {
+        match op {
+            Op::Add => a.checked_add(*b).ok_or_else(overflow),
+            Op::Sub => a.checked_sub(*b).ok_or_else(negative),
+            Op::Mul => a.checked_mul(*b).ok_or_else(overflow),
+            Op::Div => a.checked_div(*b).ok_or_else(division_by_zero),
+        }
+    }
+
+    fn is_multiple_of(&self, value: &usize, divisor: usize) -> bool {
+        value.is_multiple_of(divisor)
+    }
+}
+
+/// Worry levels modulo a multiple of every divisor the monkeys test, which
+/// keeps them small without changing where any item is thrown. Division
+/// doesn't keep the remainder, so it isn't allowed.
+///
+/// Values are worked out as `u128`, so even a modulus near `usize::MAX`
+/// can't overflow.
+///
+/// Subtraction wraps around on purpose. A remainder doesn't show whether the
+/// worry level it stands for is smaller than what's taken away, so a level
+/// that would go negative isn't caught here the way it is in [`Plain`] and
+/// [`Exact`]. Whenever the real level stays positive, the remainder wrapping
+/// round is still the right one.
+struct Modular(usize);
+
+impl Domain for Modular {
+    type Value = usize;
+
+    fn number(&self, n: usize) -> usize {
+        n % self.0
+    }
+
+    fn apply(&self, op: Op, a: &usize, b: &usize) -> Result<usize, SolveError> {
+        let (a, b, m) = (*a as u128, *b as u128, self.0 as u128);
+        let value = match op {
+            Op::Add => a + b,
+            Op::Sub => a + m - b,
+            Op::Mul => a * b,
+            Op::Div => {
+                return Err(SolveError::InvalidInput(format!(
+                    "can't divide worry levels kept modulo {m}"
+                )))
+            }
+        };
+        Ok((value % m) as usize)
+    }
+
+    fn is_multiple_of(&self, value: &usize, divisor: usize) -> bool {
+        value.is_multiple_of(divisor)
+    }
+}
+
+/// Worry levels exactly, however large they get.
+struct Exact;
+
+impl Domain for Exact {
+    type Value = BigUint;
+
+    fn number(&self, n: usize) -> BigUint {
+        BigUint::from(n)
+    }
+
+    fn apply(&self, op: Op, a: &BigUint, b: &BigUint) -> Result<BigUint, SolveError> {
+        match op {
+            Op::Add => Ok(a + b),
+            Op::Sub if a < b => Err(negative()),
+            Op::Sub => Ok(a - b),
+            Op::Mul => Ok(a * b),
+            Op::Div if *b == BigUint::ZERO => Err(division_by_zero()),
+            Op::Div => Ok(a / b),
+        }
+    }
+
+    fn is_multiple_of(&self, value: &BigUint, divisor: usize) -> bool {
+        value % divisor == BigUint::ZERO
+    }
+}
+
+impl FromInput for Day11 {
+    fn from_input(input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        let mut items = Vec::new();
+        let mut ops = Vec::new();
+        let mut test = Vec::new();
+        let mut _true = Vec::new();
+        let mut _false = Vec::new();
+        let mut count = Vec::new();
+
+        for notes in blocks(input) {
+            note(&notes, 0, "Monkey ", terminated(unsigned::<usize>, char(':')), "0:")?;
+            items.push(note(&notes, 1, "Starting items: ", comma_list(unsigned), "79, 98")?);
+            ops.push(note(&notes, 2, "Operation: new = ", parse_expr, "old * 19")?);
+
+            let divisor = note(&notes, 3, "Test: divisible by ", unsigned, "23")?;
+            if divisor == 0 {
+                let (line, text) = &notes[3];
+                return Err(ParseError::new(*line, text, "can't test divisibility by 0"));
+            }
+            test.push(divisor);
+            _true.push(note(&notes, 4, "If true: throw to monkey ", unsigned, "2")?);
+            _false.push(note(&notes, 5, "If false: throw to monkey ", unsigned, "3")?);
+            count.push(0);
+        }
+        Ok(Day11 {
+            items,
+            ops,
+            test,
+            _true,
+            _false,
+            count,
+            rounds_one: 20,
+            rounds_two: 10_000,
+            exact: false,
+        })
+    }
+}
+
+impl Day11 {
+    /// Finds which monkey an item is thrown to by monkey `i`, depending on
+    /// whether its worry level passed the monkey's test.
+    fn target(&self, i: usize, divisible: bool) -> Result<usize, SolveError> {
+        let target = match divisible {
+            true => self._true[i],
+            false => self._false[i],
+        };
+        if target >= self.items.len() {
+            return Err(SolveError::InvalidInput(format!(
+                "monkey {i} throws to missing monkey {target}"
+            )));
+        }
+        Ok(target)
+    }
+}
+
+/// The least common multiple of two numbers, if it fits in a `usize`.
+fn lcm(a: usize, b: usize) -> Option<usize> {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+    (a / x).checked_mul(b)
+}
+
+impl Day11 {
+    /// Counts how many items each monkey inspects over a number of rounds,
+    /// keeping worry levels in `domain`. With `relief`, worry levels are
+    /// divided by 3 after each inspection.
+    fn inspections<D: Domain>(
+        &self,
+        domain: &D,
+        rounds: usize,
+        relief: bool,
+    ) -> Result<Vec<usize>, SolveError> {
+        let mut count: Vec<usize> = self.count.clone();
+
+        // Items never affect each other, so each one is followed on its own,
+        // skipping ahead once it's back at a monkey with the same worry level
+        for (monkey, items) in self.items.iter().enumerate() {
+            for &worry in items.iter() {
+                let extrapolation = extrapolate_with_cycle(
+                    (monkey, domain.number(worry), self.count.clone()),
+                    |(monkey, worry, inspections)| ((*monkey, worry.clone()), inspections.clone()),
+                    |(monkey, worry, inspections)| -> Result<(), SolveError> {
+                        // Items thrown to a later monkey are inspected again this round
+                        loop {
+                            inspections[*monkey] += 1;
+                            *worry = self.ops[*monkey].eval(domain, worry)?;
+                            if relief {
+                                *worry = domain.apply(Op::Div, worry, &domain.number(3))?;
+                            }
+                            let divisible = domain.is_multiple_of(worry, self.test[*monkey]);
+                            let target = self.target(*monkey, divisible)?;
+                            let next_round = target <= *monkey;
+                            *monkey = target;
+                            if next_round {
+                                return Ok(());
+                            }
+                        }
+                    },
+                    rounds,
+                )
+                .map_err(|error| match error {
+                    CycleError::Step(error) => error,
+                    CycleError::Overflow => SolveError::InvalidInput(format!(
+                        "a monkey inspects more than {} items",
+                        usize::MAX
+                    )),
+                })?;
+                for (total, inspections) in count.iter_mut().zip(extrapolation.value) {
+                    *total = total.checked_add(inspections).ok_or_else(|| {
+                        SolveError::InvalidInput(format!(
+                            "a monkey inspects more than {} items",
+                            usize::MAX
+                        ))
+                    })?;
+                }
+            }
+        }
+        Ok(count)
+    }
+}
+
+/// Multiplies the number of items the two busiest monkeys inspected.
+fn monkey_business(mut count: Vec<usize>) -> Result<Answer, SolveError> {
+    count.sort();
+    let answer = count
+        .iter()
+        .rev()
+        .take(2)
+        .try_fold(1, |product: usize, &n| product.checked_mul(n))
+        .ok_or_else(|| {
+            SolveError::InvalidInput(format!("the monkey business is over {}", usize::MAX))
+        })?;
+    Ok(answer.into())
+}
+
+impl Solution for Day11 {
+    params!(rounds_one, rounds_two, exact);
+
+    fn part_one(&self) -> Result<Answer, SolveError> {
+        // Worry levels are divided by 3 each time, so they stay small enough
+        // to keep as they are
+        let count = match self.exact {
+            true => self.inspections(&Exact, self.rounds_one, true)?,
+            false => self.inspections(&Plain, self.rounds_one, true)?,
+        };
+        monkey_business(count)
+    }
+
+    fn part_two(&self) -> Result<Answer, SolveError> {
+        if self.exact {
+            return monkey_business(self.inspections(&Exact, self.rounds_two, false)?);
+        }
+        // Only the remainder modulo each divisor matters, so every worry level
+        // can be kept modulo the smallest number they all divide
+        let modulus = self.test.iter().try_fold(1, |m, &d| lcm(m, d)).ok_or_else(|| {
+            SolveError::InvalidInput(format!(
+                "the divisors' least common multiple is over {}, try `--param exact=true`",
+                usize::MAX
+            ))
+        })?;
+        monkey_business(self.inspections(&Modular(modulus), self.rounds_two, false)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expr(input: &str) -> Expr {
+        parse_line(1, input, parse_expr, "expected an expression").unwrap()
+    }
+
+    #[test]
+    fn test_parse_expr() {
+        let old_plus_3 = Expr::Binary(Op::Add, Box::new(Expr::Old), Box::new(Expr::Number(3)));
+        assert_eq!(expr("old + 3"), old_plus_3);
+        assert_eq!(
+            expr("old * (old + 3)"),
+            Expr::Binary(Op::Mul, Box::new(Expr::Old), Box::new(old_plus_3))
+        );
+
+        // Operators bind as usual, and chains read from left to right
+        assert_eq!(expr("2 + old * 3").eval(&Plain, &5).unwrap(), 17);
+        assert_eq!(expr("20 - old - 3").eval(&Plain, &5).unwrap(), 12);
+        assert_eq!(expr("(old+1)/2").eval(&Plain, &5).unwrap(), 3);
+
+        let error = parse_line(1, "old ^ 2", parse_expr, "expected an expression").unwrap_err();
+        assert_eq!(error.column, Some(4));
+    }
+
+    #[test]
+    fn test_domains() {
+        let square = expr("old * old - 1");
+        let exact = square.eval(&Exact, &BigUint::from(1u64 << 40)).unwrap();
+        assert_eq!(exact, BigUint::from((1u128 << 80) - 1));
+        assert_eq!(square.eval(&Modular(7), &3).unwrap(), 1);
+        assert!(Exact.is_multiple_of(&exact, 3));
+
+        assert!(expr("old - 10").eval(&Plain, &3).is_err());
+        assert!(expr("old / 0").eval(&Exact, &BigUint::from(3u8)).is_err());
+        assert!(expr("old / 2").eval(&Modular(7), &3).is_err());
+
+        // Modular subtraction wraps, agreeing with the real level whenever it
+        // stays positive, but can't catch one that goes negative
+        let minus_3 = expr("old - 3");
+        assert_eq!(minus_3.eval(&Exact, &BigUint::from(12u8)).unwrap(), BigUint::from(9u8));
+        assert_eq!(minus_3.eval(&Modular(7), &5).unwrap(), 2);
+        assert!(minus_3.eval(&Plain, &2).is_err());
+        assert_eq!(minus_3.eval(&Modular(7), &2).unwrap(), 6);
+
+        // Plain worry levels report overflow, while modular ones never do
+        assert!(expr("old * old").eval(&Plain, &(1 << 40)).is_err());
+        let m = usize::MAX - 1;
+        assert_eq!(expr("old * old + old").eval(&Modular(m), &(m - 1)).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(7, 1), Some(7));
+        assert_eq!(lcm(usize::MAX, 2), None);
+    }
+
+    /// Monkeys whose divisors share factors, so worry levels are kept modulo
+    /// 30 rather than their product.
+    const MONKEYS: &str = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 6
+    If true: throw to monkey 2
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 10
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 15
+    If true: throw to monkey 1
+    If false: throw to monkey 0";
+
+    fn monkeys() -> Day11 {
+        Day11::from_input(MONKEYS.lines().map(String::from)).unwrap()
+    }
+
+    #[test]
+    fn test_exact_agrees() {
+        let mut day = monkeys();
+        day.rounds_two = 12;
+        let modular = day.part_two().unwrap();
+        day.exact = true;
+        assert_eq!(day.part_two().unwrap(), modular);
+    }
+
+    #[test]
+    fn test_overflow() {
+        // The monkey business is too large for a `usize` long before the
+        // rounds are
+        let mut day = monkeys();
+        day.rounds_two = 100_000_000_000_000;
+        assert!(matches!(day.part_two(), Err(SolveError::InvalidInput(_))));
+        day.rounds_two = usize::MAX;
+        assert!(matches!(day.part_two(), Err(SolveError::InvalidInput(_))));
+    }
+}
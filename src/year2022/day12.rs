@@ -0,0 +1,55 @@
+use crate::error::{ParseError, SolveError};
+use crate::grid::Grid;
+use crate::search::bfs;
+use crate::utils::{Answer, FromInput, Solution};
+
+register!(12, Day12, "Hill Climbing Algorithm");
+
+pub struct Day12(pub Grid<char>);
+
+impl FromInput for Day12 {
+    fn from_input(input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        let grid = Grid::parse(
+            input,
+            |c| (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c),
+            "expected elevations a-z, S or E",
+        )?;
+        Ok(Day12(grid))
+    }
+}
+
+fn elevation(letter: char) -> u32 {
+   match letter {
+       'S' => 1,
+       'E' => 26,
+       c => u32::from(c) - u32::from('a') + 1
+   } 
+} 
+
+/// Finds the fewest steps from any of `starts` to E, climbing at most one
+/// level at a time.
+fn shortest_path(grid: &Grid<char>, starts: impl Iterator<Item = (usize, usize)>) -> Result<usize, SolveError> {
+    let climbable = |&position: &(usize, usize)| {
+        let ce = elevation(grid[position]);
+        grid.neighbours(position).filter(move |&next| elevation(grid[next]) <= ce + 1)
+    };
+    bfs(starts, climbable, |&position| grid[position] == 'E')
+        .cost()
+        .ok_or_else(|| SolveError::NoAnswer("no path reaches E".to_string()))
+}
+
+impl Solution for Day12 {
+    fn part_one(&self) -> Result<Answer, SolveError> {
+        let grid = &self.0;
+        Ok(shortest_path(grid, grid.find_all(&'S'))?.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolveError> {
+        let grid = &self.0;
+        let starts = grid
+            .iter()
+            .filter(|&(_, &letter)| elevation(letter) == 1)
+            .map(|(start, _)| start);
+        Ok(shortest_path(grid, starts)?.into())
+    }
+}
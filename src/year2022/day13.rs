@@ -1,15 +1,19 @@
 use std::cmp::Ordering;
 
-use crate::utils::{FromInput, Solution};
+use crate::error::{ParseError, SolveError};
+use crate::parsers::{parse_line, unsigned};
+use crate::utils::{Answer, FromInput, Solution};
 
 use nom::{
     branch::alt, bytes::complete::tag, combinator::map, multi::separated_list0,
     sequence::delimited, IResult,
 };
 
+register!(13, Day13, "Distress Signal");
 
+/// A packet, which is an integer or a list of packets.
 #[derive(Debug, PartialEq, Eq, Clone)]
-enum Packet {
+pub enum Packet {
     Int(usize),
     List(Vec<Packet>),
 }
@@ -38,45 +42,38 @@ impl PartialOrd for Packet {
     }
 }
 
-fn parse_usize(input: &str) -> IResult<&str, usize> {
-    map(nom::character::complete::u64, |n| n as usize)(input)
-}
-
 fn parse_packet(input: &str) -> IResult<&str, Packet> {
     alt((
-        map(parse_usize, Packet::Int),
+        map(unsigned, Packet::Int),
         map(delimited(tag("["), separated_list0(tag(","), parse_packet), tag("]")), Packet::List),
     ))(input)
 }
 
-pub struct Day13(Vec<Packet>);
+pub struct Day13(pub Vec<Packet>);
 
 impl FromInput for Day13 {
-    fn from_input(input: impl Iterator<Item = String>) -> Self {
-        let lines: Vec<String> = input.collect();
-        let mut i = lines.iter();
+    fn from_input(input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
         let mut packets = Vec::new();
+        let mut count = 0;
 
-        loop {
-            let packet1 = i.next().unwrap();
-            let packet2 = i.next().unwrap();
-           
-            let a = parse_packet(packet1.trim()).unwrap();
-            let b = parse_packet(packet2.trim()).unwrap();
-            
-            packets.push(a.1);
-            packets.push(b.1);
-
-            if i.next() == None {
-                break;
-            };
+        for (i, line) in input.enumerate() {
+            count = i + 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            packets.push(parse_line(i + 1, line.trim(), parse_packet, "expected a packet like [1,[2,3]]")?);
         }
-        Day13(packets)
+
+        if packets.len() % 2 != 0 {
+            return Err(ParseError::new(count + 1, "", "expected packets to come in pairs"));
+        }
+        Ok(Day13(packets))
     }
 }
 
 impl Solution for Day13 {
-    fn part_one(&self) -> String {
+    fn part_one(&self) -> Result<Answer, SolveError> {
         let mut answer = 0;
         let mut pair = 0;
         for i in (1..self.0.len()).step_by(2) {
@@ -85,10 +82,10 @@ impl Solution for Day13 {
                 answer += pair;
             } 
         }
-        format!("{}", answer)
+        Ok(answer.into())
     }
 
-    fn part_two(&self) -> String {
+    fn part_two(&self) -> Result<Answer, SolveError> {
         let mut answer = 1;
         let mut packets = self.0.clone();
 
@@ -99,34 +96,12 @@ impl Solution for Day13 {
         packets.push(y.clone());
 
         packets.sort();
-        answer *= packets.iter().position(|i| i == &x).unwrap() + 1;
-        answer *= packets.iter().position(|i| i == &y).unwrap() + 1;
-
-        format!("{}", answer)
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        use crate::load_input;
-        let test_input = load_input(format!(".test_input/13.txt"));
-        let day = Day13::from_input(test_input);
-
-        let result = day.part_one();
-        assert_eq!(result, "13");
-    }
-
-    #[test]
-    fn test_part_two() {
-        use crate::load_input;
-        let test_input = load_input(format!(".test_input/13.txt"));
-        let day = Day13::from_input(test_input);
+        for divider in [x, y] {
+            if let Some(position) = packets.iter().position(|i| i == &divider) {
+                answer *= position + 1;
+            }
+        }
 
-        let result = day.part_two();
-        assert_eq!(result, "140");
+        Ok(answer.into())
     }
 }
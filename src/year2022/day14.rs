@@ -0,0 +1,148 @@
+use std::cmp::{min, max};
+use crate::error::{ParseError, SolveError};
+use crate::grid::Grid;
+use crate::parsers::{parse_line, point};
+use crate::utils::{Answer, FromInput, Solution};
+use nom::{bytes::complete::tag, multi::separated_list1, IResult};
+
+register!(14, Day14, "Regolith Reservoir");
+
+/// Where the sand pours in.
+const SOURCE: (usize, usize) = (500, 0);
+
+pub struct Day14 {
+    /// The cave, where rock and sand at rest are `true`. It goes one row below
+    /// the lowest rock, where sand rests on the floor in part two, and only as
+    /// far to each side as sand can spread
+    pub cave: Grid<bool>,
+    /// Where the sand pours in, in the cave's own coordinates
+    pub source: (usize, usize),
+}
+
+fn parse_path(input: &str) -> IResult<&str, Vec<(usize, usize)>> {
+    separated_list1(tag(" -> "), point)(input)
+}
+
+impl FromInput for Day14 {
+    fn from_input(input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        let mut paths = Vec::new();
+        let mut lines = Vec::new();
+        for (i, line) in input.enumerate() {
+            paths.push(parse_line(i + 1, &line, parse_path, "expected a path like 498,4 -> 498,6")?);
+            lines.push(line);
+        }
+
+        let depths = paths.iter().map(|path| path.iter().map(|&(_, y)| y).max().unwrap_or_default());
+        let (deepest, depth) = depths.enumerate().max_by_key(|&(_, y)| y).unwrap_or_default();
+        // Sand moves at most one column to the side for each row it falls, so
+        // the cave is centred on the source and rock further out is left out,
+        // however far away it is
+        let cave = depth
+            .checked_add(2)
+            .and_then(|height| Grid::try_new(height.checked_mul(2)?.checked_add(1)?, height, false));
+        let Some(mut cave) = cave else {
+            let text = lines.swap_remove(deepest);
+            return Err(ParseError::new(deepest + 1, text, "the cave is too deep to simulate"));
+        };
+        let height = cave.height();
+        let column = |x: usize| x.saturating_add(height).checked_sub(SOURCE.0);
+
+        for path in paths.iter() {
+            for pair in path.windows(2) {
+                let (x1, y1) = pair[0];
+                let (x2, y2) = pair[1];
+                let Some(last) = column(max(x1, x2)) else {
+                    continue;
+                };
+                let first = column(min(x1, x2)).unwrap_or(0);
+                for x in first..=last.min(cave.width() - 1) {
+                    for y in min(y1, y2)..=max(y1, y2) {
+                        cave[(x, y)] = true;
+                    }
+                }
+            }
+        }
+        Ok(Day14 {
+            cave,
+            source: (height, SOURCE.1),
+        })
+    }
+}
+
+/// Drops a unit of sand from `source`, returning where it comes to rest.
+/// Sand on the bottom row is resting on the floor.
+fn drop_sand(cave: &Grid<bool>, source: (usize, usize)) -> (usize, usize) {
+    let mut sand = source;
+    while let Some(next) = [(0, 1), (-1, 1), (1, 1)]
+        .into_iter()
+        .filter_map(|step| cave.step(sand, step))
+        .find(|&next| !cave[next])
+    {
+        sand = next;
+    }
+    sand
+}
+
+impl Solution for Day14 {
+    fn part_one(&self) -> Result<Answer, SolveError> {
+        let mut cave = self.cave.clone();
+        let mut answer = 0;
+
+        while !cave[self.source] {
+            let sand = drop_sand(&cave, self.source);
+            // Below the lowest rock, sand falls into the abyss
+            if sand.1 == cave.height() - 1 {
+                break;
+            }
+            cave[sand] = true;
+            answer += 1;
+        }
+        Ok(answer.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolveError> {
+        let mut cave = self.cave.clone();
+        let mut answer = 0;
+
+        while !cave[self.source] {
+            let sand = drop_sand(&cave, self.source);
+            cave[sand] = true;
+            answer += 1;
+        }
+        Ok(answer.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cave(input: &str) -> Result<Day14, ParseError> {
+        Day14::from_input(input.lines().map(String::from))
+    }
+
+    #[test]
+    fn test_far_off_rock() {
+        // The rock is too far away for sand to reach, so it fills a triangle
+        let day = cave("1000000,5 -> 1000000,6").unwrap();
+        assert_eq!(day.part_one().unwrap(), 0);
+        assert_eq!(day.part_two().unwrap(), 8 * 8);
+    }
+
+    #[test]
+    fn test_left_of_zero() {
+        // A staircase of rock two steps thick from the source down to x=0.
+        // Sand slides all the way down it and off the left end, so at the
+        // bottom it must fall past x=0 into the abyss
+        let stairs = (0..=500).map(|x| format!("{x},{} -> {x},{}", 502 - x, 503 - x));
+        let day = cave(&stairs.collect::<Vec<_>>().join("\n")).unwrap();
+        assert_eq!(day.part_one().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_too_deep() {
+        let error = cave("500,1 -> 500,2\n1,18446744073709551615").err().unwrap();
+        assert_eq!(error.line, 2);
+        assert!(cave("1,1000000000000").is_err());
+    }
+}
@@ -0,0 +1,125 @@
+use crate::error::{ParseError, SolveError};
+use crate::intervals::IntervalSet;
+use crate::parsers::{key_value, parse_lines, signed};
+use crate::point::Point2;
+use crate::utils::{Answer, FromInput, Solution};
+
+use std::ops::RangeInclusive;
+use nom::{bytes::complete::tag, sequence::{separated_pair, tuple}, IResult, combinator::map};
+
+register!(15, Day15, "Beacon Exclusion Zone");
+
+pub struct Day15 {
+    pub pairs: Vec<SensorBeaconPair>,
+    /// The row to count the positions a beacon can't be in for part one
+    pub y_target: isize,
+    /// The largest coordinate the distress beacon can have for part two
+    pub limit: isize,
+}
+
+/// A sensor and the closest beacon to it.
+#[derive(Debug)]
+pub struct SensorBeaconPair {
+    pub sensor: Point2,
+    pub beacon: Point2,
+}
+
+fn parse_position(input: &str) -> IResult<&str, Point2> {
+    map(
+        separated_pair(key_value("x", signed), tag(", "), key_value("y", signed)),
+        Point2::from,
+    )(input)
+}
+
+fn parse_pair(input: &str) -> IResult<&str, SensorBeaconPair> {
+    map(
+        tuple((
+            tag("Sensor at "),
+            parse_position,
+            tag(": closest beacon is at "),
+            parse_position,
+        )),
+        |(_, sensor, _, beacon)| SensorBeaconPair { sensor, beacon }
+    )(input)
+}
+
+impl FromInput for Day15 {
+    fn from_input(input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        let pairs = parse_lines(input, parse_pair, "expected a sensor and its closest beacon")?;
+        Ok(Day15 {
+            pairs,
+            y_target: 2_000_000,
+            limit: 4_000_000,
+        })
+    }
+}
+
+/// The positions in row `y` the sensor can see, which can't have a beacon
+/// unless it's the sensor's closest.
+fn coverage(pair: &SensorBeaconPair, y: isize) -> RangeInclusive<isize> {
+    let d = pair.sensor.manhattan(pair.beacon);
+    let reach = d - (pair.sensor.y - y).abs();
+    pair.sensor.x - reach..=pair.sensor.x + reach
+}
+
+
+impl Solution for Day15 {
+    params!(y_target, limit);
+
+    fn part_one(&self) -> Result<Answer, SolveError> {
+        let y_target = self.y_target;
+
+        let mut covered: IntervalSet = self.pairs.iter().map(|pair| coverage(pair, y_target)).collect();
+        // Sensors and beacons on the row are known not to be the distress beacon
+        for pair in self.pairs.iter() {
+            for position in [pair.sensor, pair.beacon] {
+                if position.y == y_target {
+                    covered.remove(position.x..=position.x);
+                }
+            }
+        }
+        Ok(covered.len().into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolveError> {
+        let limit = self.limit;
+
+        fn is_not_visible(position: Point2, pairs: &[SensorBeaconPair], limit: isize) -> bool {
+            if !position.within(Point2::ORIGIN, Point2::new(limit, limit)) {
+                return false;
+            }
+
+            for pair in pairs.iter() {
+                let d = pair.sensor.manhattan(pair.beacon);
+                if pair.sensor.manhattan(position) <= d {
+                    return false;
+                }
+            }
+            
+            true
+        }
+
+        // The distress beacon is just out of reach of some sensor, so look
+        // along the edges of the area each sensor covers
+        let answer = self.pairs.iter().find_map(|pair| {
+            let d = pair.sensor.manhattan(pair.beacon);
+            let right_corner = pair.sensor + Point2::new(d + 1, 0);
+            let left_corner = pair.sensor - Point2::new(d - 1, 0);
+
+            (0..=d+1).find_map(|i| {
+                [
+                    right_corner + Point2::new(-i, -i),
+                    right_corner + Point2::new(-i, i),
+                    left_corner + Point2::new(-i, -i),
+                    left_corner + Point2::new(-i, i),
+                ]
+                .into_iter()
+                .find(|&position| is_not_visible(position, &self.pairs, limit))
+            })
+        });
+        let beacon = answer.ok_or_else(|| {
+            SolveError::NoAnswer("every position is covered by a sensor".to_string())
+        })?;
+        Ok((beacon.x * 4_000_000 + beacon.y).into())
+    }
+}
@@ -0,0 +1,172 @@
+use crate::error::{ParseError, SolveError};
+use crate::parsers::{comma_list, key_value, parse_line, unsigned};
+use crate::search::bfs;
+use crate::utils::{Answer, FromInput, Solution};
+use nom::{branch::alt, bytes::complete::tag, combinator::map, sequence::tuple, IResult};
+use std::cmp::max;
+use std::collections::HashMap;
+
+register!(16, Day16, "Proboscidea Volcanium");
+
+#[derive(Debug)]
+pub struct Day16 {
+    pub names: Vec<String>,
+    pub flows: Vec<usize>,
+    pub distances: Vec<Vec<usize>>,
+    /// The time to release pressure alone, and with the elephant's help
+    pub minutes: usize,
+    pub minutes_with_elephant: usize,
+}
+
+fn parse_valve(input: &str) -> IResult<&str, String> {
+    map(nom::character::complete::alpha1, |s: &str| s.to_string())(input)
+}
+
+fn parse_scan(input: &str) -> IResult<&str, (String, usize, Vec<String>)> {
+    map(
+        tuple((
+            tag("Valve "),
+            parse_valve,
+            tag(" has flow "),
+            key_value("rate", unsigned),
+            alt((
+                tag("; tunnels lead to valves "),
+                tag("; tunnel leads to valve "),
+            )),
+            comma_list(parse_valve),
+        )),
+        |(_, name, _, fr, _, ts)| (name, fr, ts),
+    )(input)
+}
+
+/// Finds the fewest minutes it takes to walk from each valve to every other.
+/// Valves that can't be reached are never worth walking to.
+fn distance_matrix(names: &[String], tunnels: &[Vec<String>]) -> Vec<Vec<usize>> {
+    let index: HashMap<&str, usize> = names.iter().enumerate().map(|(i, name)| (name.as_str(), i)).collect();
+    let neighbours = |&valve: &usize| tunnels[valve].iter().filter_map(|name| index.get(name.as_str()).copied());
+
+    (0..names.len())
+        .map(|valve| {
+            let search = bfs([valve], neighbours, |_| false);
+            (0..names.len()).map(|other| search.distance(&other).unwrap_or(usize::MAX)).collect()
+        })
+        .collect()
+}
+
+impl FromInput for Day16 {
+    fn from_input(input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        let mut names = Vec::new();
+        let mut flows = Vec::new();
+        let mut tunnels = Vec::new();
+
+        for (i, line) in input.enumerate() {
+            let (name, flow, tunnel_list) =
+                parse_line(i + 1, &line, parse_scan, "expected a valve and its tunnels")?;
+            names.push(name);
+            flows.push(flow);
+            tunnels.push(tunnel_list);
+        }
+        let distances = distance_matrix(&names, &tunnels);
+        Ok(Day16 {
+            names,
+            flows,
+            distances,
+            minutes: 30,
+            minutes_with_elephant: 26,
+        })
+    }
+}
+
+fn take_one2(choices: &[usize]) -> Vec<(usize, Vec<usize>)> {
+    let mut iter = Vec::new();
+
+    for i in 0..choices.len() {
+        let mut rest = choices.to_vec();
+        let choice = rest.remove(i);
+        iter.push((choice, rest));
+    }
+    iter
+}
+
+fn dfs(cur: &usize, choices: &[usize], time: &usize, distances: &[Vec<usize>], flows: &[usize]) -> usize {
+    let mut pressure = 0;
+
+    for (choice, rest) in take_one2(choices).iter() {
+        let distance = distances[*cur][*choice];
+        if distance < *time {
+            let nt = time - distance - 1;
+            pressure = max(pressure, (flows[*choice] * nt) + dfs(choice, rest, &nt, distances, flows));
+        }
+    }
+    pressure
+}
+
+/// Like `dfs`, but once we stop opening valves, the elephant opens what's
+/// left in `elephant_time` minutes, setting off from `start`.
+fn dfs2(cur: &usize, choices: &[usize], time: &usize, start: &usize, elephant_time: &usize, distances: &[Vec<usize>], flows: &[usize]) -> usize {
+    let mut pressure = 0;
+
+    for (choice, rest) in take_one2(choices).iter() {
+        let distance = distances[*cur][*choice];
+        if distance < *time {
+            let nt = time - distance - 1;
+            pressure = max(pressure, flows[*choice] * nt + dfs2(choice, rest, &nt, start, elephant_time, distances, flows));
+        }
+    }
+    let elephant = dfs(start, choices, elephant_time, distances, flows);
+    max(pressure, elephant)
+}
+
+impl Day16 {
+    fn start(&self) -> Result<usize, SolveError> {
+        self.names
+            .iter()
+            .position(|n| n == "AA")
+            .ok_or_else(|| SolveError::InvalidInput("there is no valve AA to start from".to_string()))
+    }
+
+    fn choices(&self) -> Vec<usize> {
+        self.flows.iter().enumerate().filter(|(_, &f)| f > 0).map(|(i, _)| i).collect()
+    }
+}
+
+impl Solution for Day16 {
+    params!(minutes, minutes_with_elephant);
+
+    fn part_one(&self) -> Result<Answer, SolveError> {
+        let start = self.start()?;
+        let choices = self.choices();
+        Ok(dfs(&start, &choices, &self.minutes, &self.distances, &self.flows).into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolveError> {
+        let start = self.start()?;
+        let choices = self.choices();
+        let time = &self.minutes_with_elephant;
+        Ok(dfs2(&start, &choices, time, &start, time, &self.distances, &self.flows).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_isnt_first() {
+        // The example with the scan of AA moved from the first line to the last
+        let input = "\
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II
+Valve AA has flow rate=0; tunnels lead to valves DD, II, BB";
+        let day = Day16::from_input(input.lines().map(String::from)).unwrap();
+        assert_eq!(day.part_one().unwrap(), 1651);
+        assert_eq!(day.part_two().unwrap(), 1707);
+    }
+}
@@ -0,0 +1,148 @@
+use std::cmp::max;
+use std::collections::HashSet;
+
+use crate::cycle::{extrapolate_with_cycle, CycleError};
+use crate::error::{ParseError, SolveError};
+use crate::point::Point2;
+use crate::utils::{Answer, FromInput, Solution};
+
+register!(17, Day17, "Pyroclastic Flow");
+
+pub struct Day17 {
+    pub rocks: Vec<Vec<Point2>>,
+    pub jets: Vec<Point2>,
+    /// How many rocks fall in each part
+    pub rocks_one: usize,
+    pub rocks_two: usize,
+}
+
+impl FromInput for Day17 {
+    fn from_input(input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        let rocks = [
+            vec![(0, 0), (1, 0), (2, 0), (3, 0)],
+            vec![(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+            vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+            vec![(0, 0), (0, 1), (0, 2), (0, 3)],
+            vec![(0, 0), (1, 0), (0, 1), (1, 1)],
+        ];
+        let rocks = rocks.map(|rock| rock.into_iter().map(Point2::from).collect()).to_vec();
+        let mut jets = Vec::new();
+        let mut count = 0;
+        for (i, line) in input.enumerate() {
+            count = i + 1;
+            for c in line.chars() {
+                let dir = match c {
+                    '<' => Point2::LEFT,
+                    '>' => Point2::RIGHT,
+                    _ => return Err(ParseError::new(i + 1, line, format!("invalid jet symbol `{c}`"))),
+                };
+                jets.push(dir);
+            }
+        }
+        if jets.is_empty() {
+            return Err(ParseError::new(count + 1, "", "expected a jet pattern"));
+        }
+        Ok(Day17 {rocks, jets, rocks_one: 2022, rocks_two: 1_000_000_000_000})
+    }
+}
+
+/// The rocks that have come to rest, along with which rock falls next and
+/// which jet pushes it first.
+struct Chamber<'a> {
+    rocks: &'a [Vec<Point2>],
+    jets: &'a [Point2],
+    stopped: HashSet<Point2>,
+    /// The highest rock in each column, or -1 for the floor
+    tops: [isize; 7],
+    rock: usize,
+    jet: usize,
+}
+
+impl<'a> Chamber<'a> {
+    fn new(day: &'a Day17) -> Self {
+        Chamber {
+            rocks: &day.rocks,
+            jets: &day.jets,
+            stopped: HashSet::new(),
+            tops: [-1; 7],
+            rock: 0,
+            jet: 0,
+        }
+    }
+
+    fn height(&self) -> isize {
+        self.tops.iter().max().unwrap() + 1
+    }
+
+    /// The shape of the top of the tower, as how far each column is below
+    /// the highest one.
+    fn skyline(&self) -> [isize; 7] {
+        let top = self.height() - 1;
+        self.tops.map(|y| y - top)
+    }
+
+    fn blocked(&self, p: Point2) -> bool {
+        !(0..7).contains(&p.x) || p.y < 0 || self.stopped.contains(&p)
+    }
+
+    /// Drops the next rock, pushed by the jets as it falls, until it comes to rest.
+    fn drop_rock(&mut self) {
+        let spawn = Point2::new(2, self.height() + 3);
+        let mut rock: Vec<Point2> = self.rocks[self.rock].iter().map(|&p| p + spawn).collect();
+        self.rock = (self.rock + 1) % self.rocks.len();
+
+        loop {
+            let jet = self.jets[self.jet];
+            self.jet = (self.jet + 1) % self.jets.len();
+            let pushed: Vec<Point2> = rock.iter().map(|&p| p + jet).collect();
+            if !pushed.iter().any(|&p| self.blocked(p)) {
+                rock = pushed;
+            }
+
+            let fallen: Vec<Point2> = rock.iter().map(|&p| p + Point2::DOWN).collect();
+            if fallen.iter().any(|&p| self.blocked(p)) {
+                break;
+            }
+            rock = fallen;
+        }
+
+        for p in rock {
+            self.tops[p.x as usize] = max(self.tops[p.x as usize], p.y);
+            self.stopped.insert(p);
+        }
+    }
+}
+
+impl Solution for Day17 {
+    params!(rocks_one, rocks_two);
+
+    fn part_one(&self) -> Result<Answer, SolveError> {
+        let mut chamber = Chamber::new(self);
+        for _ in 0..self.rocks_one {
+            chamber.drop_rock();
+        }
+        Ok(chamber.height().into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolveError> {
+        // The tower repeats once a rock falls onto the same shape as before,
+        // when it's the same rock and the jets are at the same place
+        let extrapolation = extrapolate_with_cycle(
+            Chamber::new(self),
+            |chamber| ((chamber.rock, chamber.jet, chamber.skyline()), chamber.height()),
+            |chamber| -> Result<(), SolveError> {
+                chamber.drop_rock();
+                Ok(())
+            },
+            self.rocks_two,
+        )
+        .map_err(|error| match error {
+            CycleError::Step(error) => error,
+            CycleError::Overflow => SolveError::InvalidInput(format!(
+                "the tower is too tall to measure after {} rocks",
+                self.rocks_two
+            )),
+        })?;
+        Ok(extrapolation.value.into())
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+
+use crate::error::{ParseError, SolveError};
+use crate::point::Point3;
+use crate::search::flood_fill;
+use crate::utils::{Answer, FromInput, Solution};
+
+register!(18, Day18, "Boiling Boulders");
+
+pub struct Day18(pub Vec<Point3>);
+
+impl FromInput for Day18 {
+    fn from_input(input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        let mut points = Vec::new();
+        for (i, line) in input.enumerate() {
+            let entry = line
+                .split(',')
+                .map(|c| c.trim().parse())
+                .collect::<Result<Vec<isize>, _>>();
+
+            match entry.as_deref() {
+                Ok(&[x, y, z]) => points.push(Point3::new(x, y, z)),
+                _ => return Err(ParseError::new(i + 1, line, "expected a cube like 2,2,2")),
+            }
+        }
+        Ok(Day18(points))
+    }
+}
+
+impl Solution for Day18 {
+    fn part_one(&self) -> Result<Answer, SolveError> {
+        let points = &self.0;
+        let mut count = 0;
+        
+        for p in points.iter() {
+            for other in points.iter() {
+                if p.manhattan(*other) == 1 {
+                    count += 1;
+                }
+            }
+        }
+
+        Ok((6 * points.len() - count).into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolveError> {
+        let Some((low, high)) = Point3::bounds(self.0.iter().copied()) else {
+            return Ok(Answer::Int(0));
+        };
+        let points: HashSet<Point3> = self.0.iter().copied().collect();
+
+        // Fill the air around the droplet, leaving room to flow around its sides
+        let one = Point3::new(1, 1, 1);
+        let (low, high) = (low - one, high + one);
+
+        let air = flood_fill([low], |p| {
+            p.neighbours().filter(|k| k.within(low, high) && !points.contains(k))
+        });
+        let faces = air
+            .iter()
+            .flat_map(|p| p.neighbours())
+            .filter(|k| points.contains(k))
+            .count();
+        Ok(faces.into())
+    }
+}
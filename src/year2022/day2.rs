@@ -0,0 +1,116 @@
+use crate::error::{ParseError, SolveError};
+use crate::utils::{Answer, FromInput, Solution};
+
+register!(2, Day2, "Rock Paper Scissors");
+
+/// A shape thrown in a round.
+#[derive(Clone, Copy)]
+pub enum Choice {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+/// The second column of the strategy guide, read differently in each part.
+#[derive(Clone, Copy)]
+pub enum Strategy {
+    X,
+    Y,
+    Z,
+}
+
+/// A round of the strategy guide.
+pub struct Game {
+    pub choice: Choice,
+    pub strategy: Strategy,
+}
+
+pub struct Day2 {
+    pub games: Vec<Game>,
+}
+
+impl FromInput for Day2 {
+    fn from_input(input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        let mut games = Vec::new();
+
+        for (i, line) in input.enumerate() {
+            let mut guide = line.split_whitespace();
+
+            let choice = match guide.next() {
+                Some("A") => Choice::Rock,
+                Some("B") => Choice::Paper,
+                Some("C") => Choice::Scissors,
+                _ => return Err(ParseError::new(i + 1, line, "expected A, B or C")),
+            };
+
+            let strategy = match guide.next() {
+                Some("X") => Strategy::X,
+                Some("Y") => Strategy::Y,
+                Some("Z") => Strategy::Z,
+                _ => return Err(ParseError::new(i + 1, line, "expected X, Y or Z")),
+            };
+
+            games.push(Game { choice, strategy });
+        }
+
+        Ok(Day2 { games })
+    }
+}
+
+impl Solution for Day2 {
+    fn part_one(&self) -> Result<Answer, SolveError> {
+        let mut score = 0;
+
+        for game in self.games.iter() {
+            score += match game.strategy {
+                Strategy::X => 1, // play Rock
+                Strategy::Y => 2, // play Paper
+                Strategy::Z => 3, // play Scissors
+            };
+
+            score += match (game.choice, game.strategy) {
+                (Choice::Rock, Strategy::X) => 3,
+                (Choice::Rock, Strategy::Y) => 6,
+                (Choice::Rock, Strategy::Z) => 0,
+
+                (Choice::Paper, Strategy::X) => 0,
+                (Choice::Paper, Strategy::Y) => 3,
+                (Choice::Paper, Strategy::Z) => 6,
+
+                (Choice::Scissors, Strategy::X) => 6,
+                (Choice::Scissors, Strategy::Y) => 0,
+                (Choice::Scissors, Strategy::Z) => 3,
+            };
+        }
+
+        Ok(score.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolveError> {
+        let mut score = 0;
+
+        for game in self.games.iter() {
+            score += match game.strategy {
+                Strategy::X => 0, // LOSS
+                Strategy::Y => 3, // DRAW
+                Strategy::Z => 6, // WIN
+            };
+
+            score += match (game.strategy, game.choice) {
+                // Must choose to lose
+                (Strategy::X, Choice::Rock) => 3,
+                (Strategy::X, Choice::Paper) => 1,
+                (Strategy::X, Choice::Scissors) => 2,
+                // Must choose to draw
+                (Strategy::Y, Choice::Rock) => 1,
+                (Strategy::Y, Choice::Paper) => 2,
+                (Strategy::Y, Choice::Scissors) => 3,
+                // Must choose to win
+                (Strategy::Z, Choice::Rock) => 2,
+                (Strategy::Z, Choice::Paper) => 3,
+                (Strategy::Z, Choice::Scissors) => 1,
+            };
+        }
+        Ok(score.into())
+    }
+}
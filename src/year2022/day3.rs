@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+use crate::error::{ParseError, SolveError};
+use crate::utils::{Answer, FromInput, Solution};
+
+register!(3, Day3, "Rucksack Reorganization");
+
+pub struct Day3(pub Vec<String>);
+
+impl FromInput for Day3 {
+    fn from_input(input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        let mut rucksacks = Vec::new();
+        for (i, line) in input.enumerate() {
+            if !line.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(ParseError::new(i + 1, line, "expected only letters"));
+            }
+            rucksacks.push(line.to_string());
+        }
+        Ok(Day3(rucksacks))
+    }
+}
+
+fn priority(letter: char) -> u32 {
+    let ord: u32 = letter.into();
+
+    if letter.is_lowercase() {
+        ord - (97-1) // a = 1, b = 2
+    } else {
+        ord - (65-27)  // A = 27, B = 28
+    }
+}
+
+impl Solution for Day3 {
+    fn part_one(&self) -> Result<Answer, SolveError> {
+    
+        let mut total = 0;
+        for rucksack in self.0.iter() {
+            let middle = rucksack.len() / 2; 
+            let (first, second) = rucksack.split_at(middle);
+
+            let first_set: HashSet<char> = HashSet::from_iter(first.chars());
+            let second_set: HashSet<char> = HashSet::from_iter(second.chars());
+
+            let mut intersection = first_set.intersection(&second_set);
+            let common_letter = intersection.next().ok_or_else(|| {
+                SolveError::InvalidInput(format!("no common item in rucksack {rucksack}"))
+            })?;
+
+            total += priority(*common_letter);
+        }
+        Ok(total.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolveError> {
+        let mut total = 0;
+
+        for group in self.0.chunks(3) {
+            if group.len() < 3 {
+                return Err(SolveError::InvalidInput(
+                    "rucksacks don't split into groups of three".to_string(),
+                ));
+            }
+
+            let mut set: HashSet<char> = HashSet::from_iter(group[0].chars());
+
+            for backpack in &group[1..] {
+                let temp_set: HashSet<char> = HashSet::from_iter(backpack.chars());
+                set = set.intersection(&temp_set).copied().collect();
+            }
+            
+            let common_letter = set.iter().next().ok_or_else(|| {
+                SolveError::InvalidInput("no badge common to a group".to_string())
+            })?;
+
+            total += priority(*common_letter);
+        }
+        Ok(total.into())
+    }
+}
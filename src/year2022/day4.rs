@@ -0,0 +1,50 @@
+use std::ops::RangeInclusive;
+
+use crate::error::{ParseError, SolveError};
+use crate::intervals::IntervalSet;
+use crate::parsers::{parse_lines, unsigned};
+use crate::utils::{Answer, FromInput, Solution};
+use nom::{character::complete::char, combinator::map, sequence::separated_pair, IResult};
+
+register!(4, Day4, "Camp Cleanup");
+
+/// The sections each pair of elves is assigned.
+pub struct Day4(pub Vec<(RangeInclusive<isize>, RangeInclusive<isize>)>);
+
+fn parse_range(input: &str) -> IResult<&str, RangeInclusive<isize>> {
+    map(separated_pair(unsigned, char('-'), unsigned), |(a, b)| a..=b)(input)
+}
+
+fn parse_pair(input: &str) -> IResult<&str, (RangeInclusive<isize>, RangeInclusive<isize>)> {
+    separated_pair(parse_range, char(','), parse_range)(input)
+}
+
+impl FromInput for Day4 {
+    fn from_input(input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        let ranges = parse_lines(input, parse_pair, "expected two ranges like 2-4,6-8")?;
+        Ok(Day4(ranges))
+    }
+}
+
+impl Solution for Day4 {
+    fn part_one(&self) -> Result<Answer, SolveError> {
+        let total = self
+            .0
+            .iter()
+            .filter(|(first, second)| {
+                IntervalSet::from(first.clone()).contains_range(second.clone())
+                    || IntervalSet::from(second.clone()).contains_range(first.clone())
+            })
+            .count();
+        Ok(total.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolveError> {
+        let total = self
+            .0
+            .iter()
+            .filter(|(first, second)| IntervalSet::from(first.clone()).overlaps(second.clone()))
+            .count();
+        Ok(total.into())
+    }
+}
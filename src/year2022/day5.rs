@@ -0,0 +1,154 @@
+use crate::error::{ParseError, SolveError};
+use crate::parsers::{blocks, parse_line, unsigned};
+use crate::utils::{Answer, FromInput, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{anychar, char, space0, space1},
+    combinator::{map, map_opt, value},
+    multi::separated_list1,
+    sequence::{delimited, tuple},
+    IResult,
+};
+use std::collections::VecDeque;
+
+register!(5, Day5, "Supply Stacks");
+
+/// Moves `num` crates from stack `from` to stack `to`, counting stacks from 0.
+pub struct MoveOp {
+    pub num: u8,
+    pub from: usize,
+    pub to: usize,
+}
+
+pub struct Day5 {
+    pub stacks: Vec<VecDeque<String>>,
+    pub moves: Vec<MoveOp>
+}
+
+/// A crate like `[A]`, or three spaces where there isn't one.
+fn parse_crate(input: &str) -> IResult<&str, Option<char>> {
+    alt((
+        map(delimited(char('['), anychar, char(']')), Some),
+        value(None, tag("   ")),
+    ))(input)
+}
+
+fn parse_crates(input: &str) -> IResult<&str, Vec<Option<char>>> {
+    separated_list1(char(' '), parse_crate)(input)
+}
+
+/// The row of stack numbers, which covers stacks which start out empty.
+fn parse_labels(input: &str) -> IResult<&str, Vec<usize>> {
+    delimited(space0, separated_list1(space1, unsigned), space0)(input)
+}
+
+/// A stack number, made to play nice with 0 based indexes.
+fn parse_stack(input: &str) -> IResult<&str, usize> {
+    map_opt(unsigned::<usize>, |n| n.checked_sub(1))(input)
+}
+
+fn parse_move(input: &str) -> IResult<&str, MoveOp> {
+    map(
+        tuple((tag("move "), unsigned, tag(" from "), parse_stack, tag(" to "), parse_stack)),
+        |(_, num, _, from, _, to)| MoveOp { num, from, to },
+    )(input)
+}
+
+impl FromInput for Day5 {
+    fn from_input(input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        let mut blocks = blocks(input).into_iter();
+        let drawing = blocks.next().unwrap_or_default();
+        let mut stacks = Vec::new();
+
+        if let Some(((line, labels), rows)) = drawing.split_last() {
+            let count = parse_line(*line, labels, parse_labels, "expected stack numbers")?.len();
+            stacks.resize(count, VecDeque::new());
+
+            for (line, row) in rows {
+                let crates = parse_line(*line, row.trim_end(), parse_crates, "expected crates like [A]")?;
+                if stacks.len() < crates.len() {
+                    stacks.resize(crates.len(), VecDeque::new());
+                }
+                for (stack, c) in stacks.iter_mut().zip(crates) {
+                    if let Some(c) = c {
+                        stack.push_front(c.to_string());
+                    }
+                }
+            }
+        }
+
+        let mut moves = Vec::new();
+        for (line, text) in blocks.flatten() {
+            moves.push(parse_line(line, &text, parse_move, "expected a move like `move 1 from 2 to 1`")?);
+        }
+        Ok(Day5 {stacks, moves})
+    }
+}
+
+/// Checks a move only refers to stacks which exist.
+fn check_move(stacks: &[VecDeque<String>], move_op: &MoveOp) -> Result<(), SolveError> {
+    if move_op.from >= stacks.len() || move_op.to >= stacks.len() {
+        return Err(SolveError::InvalidInput(format!(
+            "move between stacks {} and {} but there are only {} stacks",
+            move_op.from + 1,
+            move_op.to + 1,
+            stacks.len()
+        )));
+    }
+    if stacks[move_op.from].len() < move_op.num as usize {
+        return Err(SolveError::InvalidInput(format!(
+            "can't move {} crates from stack {}",
+            move_op.num,
+            move_op.from + 1
+        )));
+    }
+    Ok(())
+}
+
+fn top_crates(stacks: &[VecDeque<String>]) -> String {
+    let mut answer = String::new();
+    for stack in stacks.iter() {
+        if let Some(letter) = stack.back() {
+            answer.push_str(letter);
+        }
+    }
+    answer
+}
+
+impl Solution for Day5 {
+    fn part_one(&self) -> Result<Answer, SolveError> {
+        let mut stacks = self.stacks.clone();
+
+        for move_op in self.moves.iter() {
+            check_move(&stacks, move_op)?;
+            for _ in 0..move_op.num {
+                if let Some(cargo) = stacks[move_op.from].pop_back() {
+                    stacks[move_op.to].push_back(cargo);
+                }
+            }
+        }
+
+        Ok(top_crates(&stacks).into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolveError> {
+        let mut stacks = self.stacks.clone();
+
+        for move_op in self.moves.iter() {
+            check_move(&stacks, move_op)?;
+            let stack = &stacks.clone()[move_op.from];
+            let crates_to_move = stack.iter().rev().take(move_op.num as usize);
+
+            for cargo in crates_to_move.rev() {
+                stacks[move_op.to].push_back(cargo.to_owned());
+            }
+
+            for _ in 0..move_op.num {
+                stacks[move_op.from].pop_back();
+            }
+        }
+
+        Ok(top_crates(&stacks).into())
+    }
+}
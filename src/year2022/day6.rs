@@ -0,0 +1,46 @@
+use std::collections::HashSet;
+
+use crate::error::{ParseError, SolveError};
+use crate::utils::{Answer, FromInput, Solution};
+
+register!(6, Day6, "Tuning Trouble");
+
+pub struct Day6(pub String);
+
+impl FromInput for Day6 {
+    fn from_input(input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        let (i, buffer) = input
+            .enumerate()
+            .last()
+            .ok_or_else(|| ParseError::new(1, "", "expected a datastream"))?;
+        if !buffer.is_ascii() {
+            return Err(ParseError::new(i + 1, buffer, "expected ascii characters"));
+        }
+        Ok(Day6(buffer))
+    }
+}
+
+/// Finds the number of characters read before a window of
+/// `offset` distinct characters has been seen.
+fn find_marker(buffer: &str, offset: usize) -> Result<usize, SolveError> {
+    for i in 0..buffer.len().saturating_sub(offset - 1) {
+        let window = &buffer[i..i+offset];
+        let set: HashSet<char> = HashSet::from_iter(window.chars());
+        if set.len() == offset {
+            return Ok(i+offset);
+        }
+    }
+    Err(SolveError::NoAnswer(format!("no marker of {offset} distinct characters")))
+}
+
+impl Solution for Day6 {
+    fn part_one(&self) -> Result<Answer, SolveError> {
+        let answer = find_marker(&self.0, 4)?;
+        Ok(answer.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolveError> {
+        let answer = find_marker(&self.0, 14)?;
+        Ok(answer.into())
+    }
+}
@@ -0,0 +1,91 @@
+use crate::error::{ParseError, SolveError};
+use crate::utils::{Answer, FromInput, Solution};
+
+register!(7, Day7, "No Space Left On Device");
+
+pub struct Day7 {
+    pub root: Dir,
+    /// The largest directory counted by part one
+    pub max_size: usize,
+    /// The size of the whole disk
+    pub disk_size: usize,
+    /// The free space the update needs
+    pub space_needed: usize,
+}
+
+impl FromInput for Day7 {
+    fn from_input(input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        let lines: Vec<String> = input.collect();
+        let root = Dir::new(&mut lines.iter().map(AsRef::as_ref).enumerate())?;
+        Ok(Day7 {
+            root,
+            max_size: 100_000,
+            disk_size: 70_000_000,
+            space_needed: 30_000_000,
+        })
+    }
+}
+
+/// A directory, with the total size of everything in it.
+pub struct Dir {
+    pub size: usize,
+    pub sub_dirs: Vec<Dir>,
+}
+
+impl Dir {
+    fn new<'a>(lines: &mut impl Iterator<Item = (usize, &'a str)>) -> Result<Dir, ParseError> {
+        let mut size = 0;
+        let mut sub_dirs = Vec::new();
+
+        while let Some((i, line)) = lines.next() {
+            let skipable =["$ cd /", "dir", "$ ls"];
+            if skipable.iter().any(|s| line.starts_with(s)) {
+                continue;
+            } else if line == "$ cd .." {
+                break;
+            }
+            
+            let (first, _) = line.split_once(' ').ok_or_else(|| {
+                ParseError::new(i + 1, line, "expected a command or a directory listing")
+            })?;
+            if let Ok(filesize) = first.parse::<usize>() {
+                size += filesize;
+            } else if line.starts_with("$ cd ") {
+                let new_dir = Self::new(lines)?;
+                size += new_dir.size;
+                sub_dirs.push(new_dir);
+            } else {
+                return Err(ParseError::new(i + 1, line, "unknown command"));
+            }
+        }
+        Ok(Dir { size, sub_dirs })
+    }
+
+    fn recurse(&self) -> Box<dyn Iterator<Item = &Self> + '_> {
+        Box::new(std::iter::once(self).chain(self.sub_dirs.iter().flat_map(Self::recurse)))
+    }
+}
+
+impl Solution for Day7 {
+    params!(max_size, disk_size, space_needed);
+
+    fn part_one(&self) -> Result<Answer, SolveError> {
+        let sizes: Vec<usize> = self.root.recurse().map(|dir| dir.size).collect();
+        let answer = sizes.iter().filter(|&&size| size <= self.max_size).sum::<usize>();
+        Ok(answer.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolveError> {
+        let sizes: Vec<usize> = self.root.recurse().map(|dir| dir.size).collect();
+        let free = self.disk_size.checked_sub(self.root.size).ok_or_else(|| {
+            SolveError::InvalidInput("files take up more than the whole disk".to_string())
+        })?;
+        let required = self.space_needed.saturating_sub(free);
+        let answer = sizes
+            .iter()
+            .filter(|&&size| size >= required)
+            .min()
+            .ok_or_else(|| SolveError::NoAnswer("no directory frees enough space".to_string()))?;
+        Ok((*answer).into())
+    }
+}
@@ -0,0 +1,53 @@
+use crate::error::{ParseError, SolveError};
+use crate::grid::{Grid, ORTHOGONAL};
+use crate::utils::{Answer, FromInput, Solution};
+
+register!(8, Day8, "Treetop Tree House");
+
+pub struct Day8(pub Grid<u32>);
+
+impl FromInput for Day8 {
+    fn from_input(input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, |c| c.to_digit(10), "expected tree heights 0-9")?;
+        Ok(Day8(grid))
+    }
+}
+
+/// A tree is visible if every tree between it and an edge is shorter.
+fn tree_visible(grid: &Grid<u32>, tree: (usize, usize)) -> bool {
+    ORTHOGONAL
+        .into_iter()
+        .any(|step| grid.ray(tree, step).all(|other| grid[other] < grid[tree]))
+}
+
+/// Multiplies how far can be seen from a tree in each direction, up to and
+/// including the first tree at least as tall.
+fn tree_scenic_score(grid: &Grid<u32>, tree: (usize, usize)) -> usize {
+    ORTHOGONAL
+        .into_iter()
+        .map(|step| {
+            let mut seen = 0;
+            for other in grid.ray(tree, step) {
+                seen += 1;
+                if grid[other] >= grid[tree] {
+                    break;
+                }
+            }
+            seen
+        })
+        .product()
+}
+
+impl Solution for Day8 {
+    fn part_one(&self) -> Result<Answer, SolveError> {
+        let grid = &self.0;
+        let total = grid.positions().filter(|&tree| tree_visible(grid, tree)).count();
+        Ok(total.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolveError> {
+        let grid = &self.0;
+        let max = grid.positions().map(|tree| tree_scenic_score(grid, tree)).max();
+        Ok(max.unwrap_or_default().into())
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+
+use crate::error::{ParseError, SolveError};
+use crate::parsers::{parse_lines, unsigned};
+use crate::point::Point2;
+use crate::utils::{Answer, FromInput, Solution};
+use nom::{
+    character::complete::{anychar, char}, combinator::map_opt, sequence::separated_pair, IResult,
+};
+
+register!(9, Day9, "Rope Bridge");
+
+/// Each move is the step to take and the number of steps.
+pub struct Day9(pub Vec<(Point2, usize)>);
+
+impl FromInput for Day9 {
+    fn from_input(input: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        let moves = parse_lines(input, parse_move, "expected a move like `R 4`")?;
+        Ok(Day9(moves))
+    }
+}
+
+fn parse_move(input: &str) -> IResult<&str, (Point2, usize)> {
+    separated_pair(map_opt(anychar, get_change), char(' '), unsigned)(input)
+}
+
+fn get_change(d: char) -> Option<Point2> {
+    match d {
+        'U' => Some(Point2::UP),
+        'D' => Some(Point2::DOWN),
+        'L' => Some(Point2::LEFT),
+        'R' => Some(Point2::RIGHT),
+        _ => None,
+    }
+}
+
+/// Moves each knot after the head towards the knot in front of it, if they
+/// no longer touch.
+fn update(rope: &mut [Point2]) {
+    for i in 1..rope.len() {
+        let gap = rope[i - 1] - rope[i];
+        if gap.chebyshev(Point2::ORIGIN) > 1 {
+            rope[i] += gap.signum();
+        }
+    }
+}
+
+/// Counts the positions the tail of a rope of `knots` knots visits.
+fn tail_positions(moves: &[(Point2, usize)], knots: usize) -> usize {
+    let mut rope = vec![Point2::ORIGIN; knots];
+    let mut positions = HashSet::from([Point2::ORIGIN]);
+
+    for &(step, n) in moves.iter() {
+        for _ in 0..n {
+            rope[0] += step;
+            update(&mut rope);
+            positions.insert(rope[knots - 1]);
+        }
+    }
+    positions.len()
+}
+
+impl Solution for Day9 {
+    fn part_one(&self) -> Result<Answer, SolveError> {
+        Ok(tail_positions(&self.0, 2).into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolveError> {
+        Ok(tail_positions(&self.0, 10).into())
+    }
+}
@@ -0,0 +1,29 @@
+//! Runs each example listed in `tests/examples.toml` as its own test, so
+//! `cargo test day9` checks every example for day 9.
+
+use std::process::ExitCode;
+
+use adventofcode2022::examples::{Manifest, MANIFEST};
+use libtest_mimic::{Arguments, Trial};
+
+fn main() -> ExitCode {
+    let args = Arguments::from_args();
+    let manifest = match Manifest::load(MANIFEST) {
+        Ok(manifest) => manifest,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let trials = manifest
+        .examples
+        .into_iter()
+        .map(|example| {
+            // Examples without answers yet are listed, but don't run
+            let pending = example.is_pending();
+            Trial::test(example.name(), move || Ok(example.check()?)).with_ignored_flag(pending)
+        })
+        .collect();
+    libtest_mimic::run(&args, trials).exit_code()
+}